@@ -0,0 +1,78 @@
+//! Turns every example in `examples/dayNN/*.txt` into tests, expected answers come from
+//! `examples/dayNN/answers.toml` with a table per example:
+//!
+//! ```toml
+//! [example2]
+//! part_1 = "6"
+//! part_2 = "6"
+//! ```
+//!
+//! Crash inputs found by the fuzz targets, kept in `fuzz/regressions/dayNN/`, become tests
+//! that the parser and the parts of the day return without panicking.
+
+use std::env;
+use std::fmt::Write;
+use std::fs::{read_dir, read_to_string, write};
+use std::path::{Path, PathBuf};
+
+fn sorted_entries(dir: &Path) -> Vec<PathBuf> {
+    let mut entries: Vec<PathBuf> = read_dir(dir).unwrap().map(|entry| entry.unwrap().path()).collect();
+    entries.sort();
+    entries
+}
+
+fn test_name(name: &str) -> String {
+    name.chars().map(|char| if char.is_ascii_alphanumeric() { char.to_ascii_lowercase() } else { '_' }).collect()
+}
+
+fn main() {
+    let examples_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("examples");
+    println!("cargo:rerun-if-changed={}", examples_dir.display());
+
+    let mut tests = String::new();
+    for day_dir in sorted_entries(&examples_dir) {
+        let Some(day) = day_dir.file_name().unwrap().to_str().unwrap().strip_prefix("day") else { continue; };
+        let day: u8 = day.parse().unwrap_or_else(|_| panic!("{} is not a dayNN directory", day_dir.display()));
+        let answers_path = day_dir.join("answers.toml");
+        let answers: toml::Table = match read_to_string(&answers_path) {
+            Ok(content) => content.parse().unwrap_or_else(|err| panic!("{}: {}", answers_path.display(), err)),
+            Err(_) => toml::Table::new(),
+        };
+
+        for example in sorted_entries(&day_dir) {
+            if example.extension().is_none_or(|extension| extension != "txt") {
+                continue;
+            }
+            let name = example.file_stem().unwrap().to_str().unwrap();
+            let prefix = format!("day{:02}_{}", day, test_name(name));
+            let path = example.display();
+            writeln!(tests, "#[test]\nfn {}_parses() {{ parses({}, include_str!({:?})); }}\n", prefix, day, path).unwrap();
+            let Some(expected) = answers.get(name) else { continue; };
+            for (key, part) in [("part_1", "One"), ("part_2", "Two")] {
+                let Some(answer) = expected.get(key) else { continue; };
+                let answer = answer.as_str().unwrap_or_else(|| panic!("{}: {}.{} must be a string", answers_path.display(), name, key));
+                writeln!(
+                    tests,
+                    "#[test]\nfn {}_{}() {{ check({}, include_str!({:?}), Part::{}, {:?}); }}\n",
+                    prefix, key, day, path, part, answer,
+                ).unwrap();
+            }
+        }
+    }
+
+    let regressions_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("fuzz").join("regressions");
+    println!("cargo:rerun-if-changed={}", regressions_dir.display());
+    if regressions_dir.exists() {
+        for day_dir in sorted_entries(&regressions_dir) {
+            let Some(day) = day_dir.file_name().unwrap().to_str().unwrap().strip_prefix("day") else { continue; };
+            let day: u8 = day.parse().unwrap_or_else(|_| panic!("{} is not a dayNN directory", day_dir.display()));
+            for crash in sorted_entries(&day_dir) {
+                let name = test_name(crash.file_name().unwrap().to_str().unwrap());
+                let path = crash.display();
+                writeln!(tests, "#[test]\nfn day{:02}_fuzz_{}() {{ survives({}, include_bytes!({:?})); }}\n", day, name, day, path).unwrap();
+            }
+        }
+    }
+
+    write(Path::new(&env::var("OUT_DIR").unwrap()).join("examples.rs"), tests).unwrap();
+}
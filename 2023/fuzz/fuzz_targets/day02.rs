@@ -0,0 +1,13 @@
+#![no_main]
+
+use advent_of_code_2023::YEAR;
+use aoc_shared::registry::Part;
+use libfuzzer_sys::fuzz_target;
+
+// any text is either rejected with an error or answered, never a panic, the parts included
+fuzz_target!(|input: &str| {
+    if let Ok(parsed) = YEAR.find(2).unwrap().parse(input) {
+        let _ = parsed.part(Part::One);
+        let _ = parsed.part(Part::Two);
+    }
+});
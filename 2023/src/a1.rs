@@ -0,0 +1,810 @@
+use std::cmp::Reverse;
+use std::collections::VecDeque;
+use std::fs::read_to_string;
+use std::iter;
+use std::str::FromStr;
+
+use crate::mapped::Chunked;
+use crate::parse::{lines, Line, ParseError};
+use crate::registry::Part;
+use crate::render::{highlight, Style};
+use crate::settings::Settings;
+use crate::stream::Streaming;
+use crate::{warn, Solution};
+
+pub struct Day1;
+
+const DIGITS: [(&str, u32); 10] =
+    [("0", 0), ("1", 1), ("2", 2), ("3", 3), ("4", 4), ("5", 5), ("6", 6), ("7", 7), ("8", 8), ("9", 9)];
+
+/// Nine digits, like the longest run of digits that is one token.
+const MAX_VALUE: u32 = 999_999_999;
+
+const ROMAN: [(&str, u32); 13] = [
+    ("M", 1000), ("CM", 900), ("D", 500), ("CD", 400), ("C", 100), ("XC", 90), ("L", 50),
+    ("XL", 40), ("X", 10), ("IX", 9), ("V", 5), ("IV", 4), ("I", 1),
+];
+
+/// Built-in vocabularies by name, in the format of word-list files.
+const PRESETS: [(&str, &str); 3] = [
+    ("english", include_str!("../vocabularies/english.txt")),
+    ("czech", include_str!("../vocabularies/czech.txt")),
+    ("german", include_str!("../vocabularies/german.txt")),
+];
+
+/// What the calibration values are made of.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Tokens {
+    /// Single digits, and in part 2 the words for 0 to 9.
+    #[default]
+    Digits,
+    /// Runs of up to nine digits, and in part 2 also words of any value, a tens word followed
+    /// by a unit word like `twentyone` or `forty-two`, and Roman numerals in capitals like `XIV`.
+    Extended,
+}
+
+impl FromStr for Tokens {
+    type Err = String;
+
+    fn from_str(inp: &str) -> Result<Self, Self::Err> {
+        match inp {
+            "digits" => Ok(Tokens::Digits),
+            "extended" => Ok(Tokens::Extended),
+            _ => Err(format!("tokens must be digits or extended, not {:?}", inp)),
+        }
+    }
+}
+
+/// What becomes of a line without anything to calibrate, each of them is reported.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Digitless {
+    /// The line counts as 0.
+    #[default]
+    Zero,
+    /// The line is left out, which differs from [`Digitless::Zero`] only in the explanation.
+    Skip,
+    /// The part fails, the other one still has an answer when it can calibrate the line.
+    Error,
+}
+
+impl FromStr for Digitless {
+    type Err = String;
+
+    fn from_str(inp: &str) -> Result<Self, Self::Err> {
+        match inp {
+            "zero" => Ok(Digitless::Zero),
+            "skip" => Ok(Digitless::Skip),
+            "error" => Ok(Digitless::Error),
+            _ => Err(format!("digitless must be zero, skip or error, not {:?}", inp)),
+        }
+    }
+}
+
+/// Words spelling out numbers, any number of them per value.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Vocabulary {
+    words: Vec<(String, u32)>,
+}
+
+impl Default for Vocabulary {
+    fn default() -> Self {
+        Vocabulary::preset("english").unwrap()
+    }
+}
+
+impl Vocabulary {
+    /// Lines of `<value>: <word>...`, blank lines and `#` comments are skipped.
+    pub fn parse(list: &str) -> Result<Self, ParseError> {
+        let mut words = vec![];
+        for line in lines(list) {
+            let text = line.text.trim();
+            if text.is_empty() || text.starts_with('#') {
+                continue;
+            }
+            let (number, spellings) = line.split_once(text, ":")?;
+            let value: u32 = line.number(number.trim())?;
+            if value > MAX_VALUE {
+                return Err(line.error(number.trim(), format!("a value must be 0 to {}", MAX_VALUE)));
+            }
+            if spellings.trim().is_empty() {
+                return Err(line.error(text, format!("no words for {}", value)));
+            }
+            words.extend(spellings.split_whitespace().map(|word| (word.to_string(), value)));
+        }
+        Ok(Vocabulary { words })
+    }
+
+    pub fn preset(name: &str) -> Option<Self> {
+        let (_, list) = PRESETS.iter().find(|(preset, _)| *preset == name)?;
+        Some(Vocabulary::parse(list).expect("a valid preset"))
+    }
+
+    /// A preset by name, or a word-list file.
+    pub fn load(name_or_path: &str) -> Result<Self, String> {
+        if let Some(vocabulary) = Vocabulary::preset(name_or_path) {
+            return Ok(vocabulary);
+        }
+        let list = read_to_string(name_or_path).map_err(|err| {
+            let presets: Vec<_> = PRESETS.iter().map(|(name, _)| *name).collect();
+            format!("{:?} is no vocabulary of {} nor a readable word list: {}", name_or_path, presets.join(", "), err)
+        })?;
+        Vocabulary::parse(&list).map_err(|err| format!("{}: {}", name_or_path, err))
+    }
+
+    /// Digits and words, of digit tokens only the words for 0 to 9.
+    fn part_2_scanner(&self, tokens: Tokens) -> Scanner {
+        let words = self.words.iter().map(|(word, value)| (word.as_str(), *value));
+        let words = words.filter(|&(_, value)| tokens == Tokens::Extended || value <= 9);
+        Scanner::new(&DIGITS.into_iter().chain(words).collect::<Vec<_>>(), tokens, true)
+    }
+}
+
+/// The keys of the settings of this day, see [`Options`].
+pub const VOCABULARY: &str = "vocabulary";
+pub const TOKENS: &str = "tokens";
+pub const DIGITLESS: &str = "digitless";
+
+/// How both parts calibrate, the puzzle as written unless `--set` changes it.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Options {
+    pub vocabulary: Vocabulary,
+    pub tokens: Tokens,
+    pub digitless: Digitless,
+}
+
+impl Options {
+    /// Those of `settings`, the default for any of them not set.
+    pub fn from_settings(settings: &Settings) -> Self {
+        Options {
+            vocabulary: settings.get::<Vocabulary>(VOCABULARY).cloned().unwrap_or_default(),
+            tokens: settings.get::<Tokens>(TOKENS).copied().unwrap_or_default(),
+            digitless: settings.get::<Digitless>(DIGITLESS).copied().unwrap_or_default(),
+        }
+    }
+
+    /// The scanner of `part`.
+    fn scanner(&self, part: Part) -> Scanner {
+        match part {
+            Part::One => Scanner::part_1(self.tokens),
+            Part::Two => self.vocabulary.part_2_scanner(self.tokens),
+        }
+    }
+}
+
+/// A match of one of the patterns, or a run, `start` in bytes.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Token {
+    start: usize,
+    len: usize,
+    value: u32,
+}
+
+impl Token {
+    fn end(&self) -> usize {
+        self.start + self.len
+    }
+}
+
+/// Aho-Corasick automaton over the bytes of the patterns, finding all of them in one pass,
+/// overlapping ones included. A match of valid UTF-8 in valid UTF-8 always starts and ends
+/// at char boundaries, so bytes are as good as chars here.
+pub struct Matcher {
+    /// Next state for every state and byte, failure links already followed.
+    next: Vec<[u32; 256]>,
+    /// Length and value of the patterns ending in every state, through suffixes too.
+    matches: Vec<Vec<(usize, u32)>>,
+    /// Length of the longest pattern.
+    longest: usize,
+}
+
+impl Matcher {
+    pub fn new(patterns: &[(&str, u32)]) -> Self {
+        Matcher::from_bytes(patterns.iter().map(|&(pattern, value)| (pattern.as_bytes().to_vec(), value)).collect())
+    }
+
+    /// For [`Matcher::last`], the patterns spelled backwards byte by byte.
+    fn reversed(patterns: &[(&str, u32)]) -> Self {
+        Matcher::from_bytes(patterns.iter().map(|&(pattern, value)| (pattern.bytes().rev().collect(), value)).collect())
+    }
+
+    fn from_bytes(patterns: Vec<(Vec<u8>, u32)>) -> Self {
+        // the trie, 0 is the root and also means no child yet
+        let mut next = vec![[0u32; 256]];
+        let mut matches = vec![vec![]];
+        for (pattern, value) in &patterns {
+            let mut state = 0;
+            for &byte in pattern {
+                if next[state][byte as usize] == 0 {
+                    next.push([0; 256]);
+                    matches.push(vec![]);
+                    next[state][byte as usize] = next.len() as u32 - 1;
+                }
+                state = next[state][byte as usize] as usize;
+            }
+            matches[state].push((pattern.len(), *value));
+        }
+
+        // breadth first, so the failure state of a child is final before the child is visited
+        let mut fail = vec![0; next.len()];
+        let mut queue: VecDeque<usize> = next[0].iter().filter(|&&child| child != 0).map(|&child| child as usize).collect();
+        while let Some(state) = queue.pop_front() {
+            let inherited = matches[fail[state]].clone();
+            matches[state].extend(inherited);
+            let fallback = next[fail[state]];
+            for (child, &fallback) in next[state].iter_mut().zip(&fallback) {
+                if *child == 0 {
+                    *child = fallback;
+                } else {
+                    fail[*child as usize] = fallback as usize;
+                    queue.push_back(*child as usize);
+                }
+            }
+        }
+        let longest = patterns.iter().map(|(pattern, _)| pattern.len()).max().unwrap_or(0);
+        Matcher { next, matches, longest }
+    }
+
+    /// Every match in `line`, ordered by where they end.
+    fn tokens<'a>(&'a self, line: &'a str) -> impl Iterator<Item=Token> + 'a {
+        let mut state = 0;
+        line.bytes().enumerate().flat_map(move |(index, byte)| {
+            state = self.next[state][byte as usize] as usize;
+            self.matches[state].iter().map(move |&(len, value)| Token { start: index + 1 - len, len, value })
+        })
+    }
+
+    /// The token starting first, the longest of those starting there. The scan stops once no
+    /// token can start earlier any more, the longest pattern past the start of the best one.
+    fn first(&self, line: &str) -> Option<Token> {
+        let mut state = 0;
+        let mut first: Option<Token> = None;
+        for (index, byte) in line.bytes().enumerate() {
+            if first.is_some_and(|first| index >= first.start + self.longest) {
+                break;
+            }
+            state = self.next[state][byte as usize] as usize;
+            for &(len, value) in &self.matches[state] {
+                let token = Token { start: index + 1 - len, len, value };
+                if first.is_none_or(|first| (token.start, Reverse(token.len)) < (first.start, Reverse(first.len))) {
+                    first = Some(token);
+                }
+            }
+        }
+        first
+    }
+
+    /// The token ending last, the longest of those ending there, for a [reversed](Matcher::reversed)
+    /// matcher scanning from the end of `line` back. It stops like [`Matcher::first`].
+    fn last(&self, line: &str) -> Option<Token> {
+        let mut state = 0;
+        let mut last: Option<Token> = None;
+        for (at, byte) in line.bytes().enumerate().rev() {
+            // the tokens found from here on start at `at` or before
+            if last.is_some_and(|last| at + self.longest < last.end()) {
+                break;
+            }
+            state = self.next[state][byte as usize] as usize;
+            for &(len, value) in &self.matches[state] {
+                let token = Token { start: at, len, value };
+                if last.is_none_or(|last| (token.end(), token.len) > (last.end(), last.len)) {
+                    last = Some(token);
+                }
+            }
+        }
+        last
+    }
+}
+
+/// The token starting first and the one ending last, the longest of those starting or ending at the same place.
+fn first_and_last(tokens: impl Iterator<Item=Token>) -> Option<(Token, Token)> {
+    tokens.fold(None, |ends, token| match ends {
+        None => Some((token, token)),
+        Some((first, last)) => Some((
+            if (token.start, Reverse(token.len)) < (first.start, Reverse(first.len)) { token } else { first },
+            if (token.end(), token.len) > (last.end(), last.len) { token } else { last },
+        )),
+    })
+}
+
+/// Maximal runs of the bytes `within` accepts, those `value` gives a value are tokens.
+/// `within` must only accept ASCII, so the runs start and end at char boundaries.
+fn runs<'a>(line: &'a str, within: fn(u8) -> bool, value: fn(&str) -> Option<u32>) -> impl Iterator<Item=Token> + 'a {
+    let bytes = line.as_bytes();
+    let mut end = 0;
+    iter::from_fn(move || loop {
+        let start = end + bytes[end..].iter().position(|&byte| within(byte))?;
+        end = bytes[start..].iter().position(|&byte| !within(byte)).map_or(bytes.len(), |len| start + len);
+        if let Some(value) = value(&line[start..end]) {
+            return Some(Token { start, len: end - start, value });
+        }
+    })
+}
+
+/// A Roman numeral written the usual way, `XIV` or `MCMXC` but neither `IIII` nor `IC`.
+fn roman(numeral: &str) -> Option<u32> {
+    let (mut rest, mut value) = (numeral, 0);
+    for (symbol, symbol_value) in ROMAN {
+        while let Some(after) = rest.strip_prefix(symbol) {
+            (rest, value) = (after, value + symbol_value);
+        }
+    }
+    // the greedy reading takes some unusual numerals too, only the usual ones are written back the same
+    let mut usual = String::new();
+    let mut left = value;
+    for (symbol, symbol_value) in ROMAN {
+        while left >= symbol_value {
+            usual.push_str(symbol);
+            left -= symbol_value;
+        }
+    }
+    (rest.is_empty() && (1..4000).contains(&value) && usual == numeral).then_some(value)
+}
+
+/// The digits of `first` followed by those of `last`, so 1 and 2 give 12 and 12 and 345 give 12345.
+pub fn concatenate(first: u32, last: u32) -> u64 {
+    let digits = last.checked_ilog10().unwrap_or(0) + 1;
+    first as u64 * 10u64.pow(digits) + last as u64
+}
+
+/// Finds the tokens of a line, the matches of its patterns and for extended tokens the runs
+/// and compounds too.
+pub struct Scanner {
+    matcher: Matcher,
+    /// The same patterns backwards, for finding the last digit token from the end of a line.
+    reversed: Matcher,
+    tokens: Tokens,
+    /// Roman numerals, only part 2 has them.
+    roman: bool,
+}
+
+impl Scanner {
+    fn new(patterns: &[(&str, u32)], tokens: Tokens, roman: bool) -> Self {
+        Scanner { matcher: Matcher::new(patterns), reversed: Matcher::reversed(patterns), tokens, roman }
+    }
+
+    /// Digits only, no words.
+    fn part_1(tokens: Tokens) -> Self {
+        Scanner::new(&DIGITS, tokens, false)
+    }
+
+    /// The matches, tens and units words right after each other, runs of digits and Roman numerals.
+    fn extended_tokens(&self, line: &str) -> Vec<Token> {
+        let mut tokens: Vec<Token> = self.matcher.tokens(line).collect();
+        let mut units: Vec<Token> = tokens
+            .iter()
+            .filter(|unit| (1..=9).contains(&unit.value) && !line.as_bytes()[unit.start].is_ascii_digit())
+            .copied()
+            .collect();
+        units.sort_by_key(|unit| unit.start);
+        let compounds: Vec<Token> = tokens
+            .iter()
+            .filter(|tens| (20..100).contains(&tens.value) && tens.value % 10 == 0)
+            .flat_map(|tens| {
+                let at = if line[tens.end()..].starts_with('-') { tens.end() + 1 } else { tens.end() };
+                let from = units.partition_point(|unit| unit.start < at);
+                units[from..].iter().take_while(move |unit| unit.start == at).map(move |unit| Token {
+                    start: tens.start,
+                    len: unit.end() - tens.start,
+                    value: tens.value + unit.value,
+                })
+            })
+            .collect();
+        tokens.extend(compounds);
+        tokens.extend(runs(line, |byte| byte.is_ascii_digit(), |run| if run.len() <= 9 { run.parse().ok() } else { None }));
+        if self.roman {
+            tokens.extend(runs(line, |byte| b"IVXLCDM".contains(&byte), roman));
+        }
+        tokens
+    }
+
+    /// Digit tokens are looked for from both ends of the line, which mostly leaves its middle
+    /// alone. Extended ones need all the matches for their compounds, runs are found whole too.
+    fn first_and_last(&self, line: &str) -> Option<(Token, Token)> {
+        match self.tokens {
+            Tokens::Digits => Some((self.matcher.first(line)?, self.reversed.last(line)?)),
+            Tokens::Extended => first_and_last(self.extended_tokens(line).into_iter()),
+        }
+    }
+
+    /// The first token [concatenated](concatenate) with the last one, the usual 10 × first + last
+    /// for digits. A line of one token uses it twice, a line without any has no calibration.
+    pub fn calibration(&self, line: &str) -> Option<u64> {
+        let (first, last) = self.first_and_last(line)?;
+        Some(concatenate(first.value, last.value))
+    }
+}
+
+fn nothing_to_calibrate(line: &Line, part: Part) -> ParseError {
+    line.error(line.text, format!("nothing to calibrate for part {}", part))
+}
+
+/// Warns of a line with nothing to calibrate for `part`, an error of the part instead is up to the caller.
+fn report(line: &Line, part: Part, digitless: Digitless) {
+    match digitless {
+        Digitless::Zero => warn!("day 1 line {}: nothing to calibrate for part {}, it counts as 0", line.number, part),
+        Digitless::Skip => warn!("day 1 line {}: nothing to calibrate for part {}, it is skipped", line.number, part),
+        Digitless::Error => {}
+    }
+}
+
+/// The first line with nothing to calibrate for `part`.
+fn first_digitless(lines: &[String], options: &Options, part: Part) -> Result<(), ParseError> {
+    let scanner = options.scanner(part);
+    let mut lines = lines.iter().enumerate().map(|(index, text)| Line { number: index + 1, text });
+    match lines.find(|line| scanner.calibration(line.text).is_none()) {
+        Some(line) => Err(nothing_to_calibrate(&line, part)),
+        None => Ok(()),
+    }
+}
+
+/// The sum of the calibrations of `part`, a digitless line [reported](report) and counted as 0.
+fn total(lines: &[String], options: &Options, part: Part) -> u128 {
+    let scanner = options.scanner(part);
+    let calibration = |(index, text): (usize, &String)| match scanner.calibration(text) {
+        Some(calibration) => u128::from(calibration),
+        None => {
+            report(&Line { number: index + 1, text }, part, options.digitless);
+            0
+        }
+    };
+    lines.iter().enumerate().map(calibration).sum()
+}
+
+/// Every line with its calibrations, and the first and the last token of part 2 highlighted.
+fn explain(lines: &[String], options: &Options) -> String {
+    let scanners = [options.scanner(Part::One), options.scanner(Part::Two)];
+    let cell = |calibration: Option<u64>| match (calibration, options.digitless) {
+        (Some(value), _) => (value.to_string(), Style::Plain),
+        (None, Digitless::Skip) => ("-".to_string(), Style::Warn),
+        (None, _) => ("0".to_string(), Style::Warn),
+    };
+    let cells: Vec<Vec<(String, Style)>> =
+        lines.iter().map(|line| scanners.iter().map(|scanner| cell(scanner.calibration(line))).collect()).collect();
+    let widths: Vec<usize> = (0..2).map(|part| cells.iter().map(|row| row[part].0.len()).max().unwrap_or(0)).collect();
+    let mut explained = String::new();
+    for (line, row) in lines.iter().zip(&cells) {
+        for ((value, style), &width) in row.iter().zip(&widths) {
+            explained += &format!("{} ", style.paint_padded(value, width, false));
+        }
+        let spans = match scanners[1].first_and_last(line) {
+            Some((first, last)) if first == last => vec![(first.start..first.end(), Style::Good)],
+            Some((first, last)) => vec![(first.start..first.end(), Style::Good), (last.start..last.end(), Style::Accent)],
+            None => vec![],
+        };
+        explained += &format!(" {}\n", highlight(line, &spans));
+    }
+    explained
+}
+
+/// The lines of the input, and how to calibrate them.
+pub struct Document {
+    lines: Vec<String>,
+    options: Options,
+}
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+
+    type Input = Document;
+    // nine digits twice per line add up quickly
+    type Answer1 = u128;
+    type Answer2 = u128;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Day1::parse_with(input, &Settings::default())
+    }
+
+    fn parse_with(input: &str, settings: &Settings) -> Result<Self::Input, ParseError> {
+        Ok(Document { lines: input.lines().map(String::from).collect(), options: Options::from_settings(settings) })
+    }
+
+    /// Scans the lines once more than the part does, only under [`Digitless::Error`].
+    fn check(document: &Self::Input, part: Part) -> Result<(), ParseError> {
+        match document.options.digitless {
+            Digitless::Error => first_digitless(&document.lines, &document.options, part),
+            _ => Ok(()),
+        }
+    }
+
+    fn part_1(document: &Self::Input) -> u128 {
+        total(&document.lines, &document.options, Part::One)
+    }
+
+    fn part_2(document: &Self::Input) -> u128 {
+        total(&document.lines, &document.options, Part::Two)
+    }
+
+    fn visualize(document: &Self::Input) -> Option<String> {
+        Some(explain(&document.lines, &document.options))
+    }
+}
+
+/// The sums of both parts so far, and the first line each part has nothing to calibrate in
+/// when that is an error.
+pub struct Calibration {
+    scanners: [Scanner; 2],
+    digitless: Digitless,
+    totals: [u128; 2],
+    errors: [Option<ParseError>; 2],
+}
+
+impl Calibration {
+    fn new(options: &Options) -> Self {
+        let scanners = [options.scanner(Part::One), options.scanner(Part::Two)];
+        Calibration { scanners, digitless: options.digitless, totals: [0; 2], errors: [None, None] }
+    }
+}
+
+impl Default for Calibration {
+    fn default() -> Self {
+        Calibration::new(&Options::default())
+    }
+}
+
+impl Streaming for Day1 {
+    type State = Calibration;
+
+    fn start(settings: &Settings) -> Self::State {
+        Calibration::new(&Options::from_settings(settings))
+    }
+
+    fn feed(state: &mut Self::State, line: Line) -> Result<(), ParseError> {
+        for (index, part) in [Part::One, Part::Two].into_iter().enumerate() {
+            match state.scanners[index].calibration(line.text) {
+                Some(calibration) => state.totals[index] += u128::from(calibration),
+                None if state.digitless == Digitless::Error => {
+                    state.errors[index].get_or_insert_with(|| nothing_to_calibrate(&line, part));
+                }
+                None => report(&line, part, state.digitless),
+            }
+        }
+        Ok(())
+    }
+
+    fn check_state(state: &Self::State, part: Part) -> Result<(), ParseError> {
+        match &state.errors[part as usize - 1] {
+            Some(err) => Err(err.clone()),
+            None => Ok(()),
+        }
+    }
+
+    fn finish(state: Self::State) -> (u128, u128) {
+        (state.totals[0], state.totals[1])
+    }
+}
+
+impl Chunked for Day1 {
+    fn merge(state: &mut Self::State, next: Self::State) {
+        for (part, next_error) in next.errors.into_iter().enumerate() {
+            state.totals[part] += next.totals[part];
+            // the earlier chunk has the first line
+            if state.errors[part].is_none() {
+                state.errors[part] = next_error;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use crate::mapped::Chunked;
+    use crate::parse::Line;
+    use crate::registry::Part;
+    use crate::settings::Settings;
+    use crate::stream::Streaming;
+    use crate::Solution;
+    use super::{
+        concatenate, explain, first_and_last, first_digitless, roman, Calibration, Day1, Digitless, Options, Scanner, Token, Tokens,
+        Vocabulary, DIGITLESS, DIGITS,
+    };
+
+    fn matcher() -> Scanner {
+        Vocabulary::default().part_2_scanner(Tokens::Digits)
+    }
+
+    fn extended() -> Scanner {
+        Vocabulary::default().part_2_scanner(Tokens::Extended)
+    }
+
+    #[test]
+    fn overlapping_words_test() {
+        let matcher = matcher();
+        assert_eq!(matcher.calibration("twone"), Some(21));
+        assert_eq!(matcher.calibration("eightwo"), Some(82));
+        assert_eq!(matcher.calibration("xtwone3four"), Some(24));
+        assert_eq!(matcher.calibration("oneight"), Some(18));
+        assert_eq!(matcher.calibration("7"), Some(77));
+        assert_eq!(matcher.calibration("seve"), None);
+        assert_eq!(matcher.matcher.tokens("twone").collect::<Vec<_>>(), [
+            Token { start: 0, len: 3, value: 2 },
+            Token { start: 2, len: 3, value: 1 },
+        ]);
+    }
+
+    #[test]
+    fn unicode_test() {
+        let matcher = matcher();
+        assert_eq!(matcher.calibration("žluťoučký kůň one ✓ 7 čtyři"), Some(17));
+        assert_eq!(matcher.calibration("ñtwoñ"), Some(22));
+        assert_eq!(matcher.calibration("ěščř"), None);
+        // a word hidden by a multi-byte char in the middle is no word
+        assert_eq!(matcher.calibration("5 on\u{e9} tw\u{301}o"), Some(55));
+    }
+
+    #[test]
+    fn contained_patterns_test() {
+        let scanner = |patterns| Scanner::new(patterns, Tokens::Digits, false);
+        let matcher = scanner(&[("eleven", 11), ("even", 4), ("leve", 7)]);
+        assert_eq!(matcher.first_and_last("eleven").map(|(first, last)| (first.value, last.value)), Some((11, 11)));
+        assert_eq!(matcher.first_and_last("leven").map(|(first, last)| (first.value, last.value)), Some((7, 4)));
+        let matcher = scanner(&[("ab", 1), ("abc", 2)]);
+        assert_eq!(matcher.calibration("abc"), Some(22));
+    }
+
+    #[test]
+    fn presets_test() {
+        let czech = Vocabulary::preset("czech").unwrap();
+        assert_eq!(czech.part_2_scanner(Tokens::Digits).calibration("dvěstě a jedenáct"), Some(21));
+        assert_eq!(czech.part_2_scanner(Tokens::Digits).calibration("osmnáct"), Some(88));
+        assert_eq!(czech.part_2_scanner(Tokens::Digits).calibration("třicet čtyři"), Some(34));
+        let german = Vocabulary::preset("german").unwrap();
+        assert_eq!(german.part_2_scanner(Tokens::Digits).calibration("einszwo"), Some(12));
+        assert_eq!(german.part_2_scanner(Tokens::Digits).calibration("fünfundneunzig"), Some(59));
+        assert_eq!(Scanner::part_1(Tokens::Digits).calibration("fünf 3 neun"), Some(33));
+        assert!(Vocabulary::preset("klingon").is_none());
+    }
+
+    #[test]
+    fn word_list_test() {
+        let vocabulary = Vocabulary::parse("# Roman\n\n1: i\n2: ii  \n 5 : v\n").unwrap();
+        assert_eq!(vocabulary.words, [("i".to_string(), 1), ("ii".to_string(), 2), ("v".to_string(), 5)]);
+        assert_eq!(vocabulary.part_2_scanner(Tokens::Digits).calibration("xvii"), Some(52));
+
+        let error = Vocabulary::parse("1: one\n1000000000: milliard").unwrap_err();
+        assert_eq!((error.line, error.snippet.as_str()), (2, "1000000000"));
+        let error = Vocabulary::parse("1 one").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 1));
+        let error = Vocabulary::parse("x: one").unwrap_err();
+        assert_eq!(error.snippet, "x");
+        assert!(Vocabulary::parse("3:").is_err());
+        assert!(Vocabulary::load("english").is_ok());
+        assert!(Vocabulary::load("/no/such/words.txt").unwrap_err().contains("english, czech, german"));
+    }
+
+    #[test]
+    fn extended_tokens_test() {
+        let scanner = extended();
+        assert_eq!(scanner.calibration("eleven"), Some(1111));
+        assert_eq!(scanner.calibration("seventeen and 3"), Some(173));
+        assert_eq!(scanner.calibration("twentyone"), Some(2121));
+        assert_eq!(scanner.calibration("forty-two or 7"), Some(427));
+        assert_eq!(scanner.calibration("ninety- five"), Some(905));
+        assert_eq!(scanner.calibration("twenty1"), Some(201));
+        assert_eq!(scanner.calibration("x42y"), Some(4242));
+        assert_eq!(scanner.calibration("one 2024"), Some(12024));
+        assert_eq!(scanner.calibration("3 then 0"), Some(30));
+        // too long for a token, its digits are tokens on their own
+        assert_eq!(scanner.calibration("1234567890"), Some(10));
+        assert_eq!(scanner.calibration("XIV then MCMXC"), Some(141990));
+        assert_eq!(scanner.calibration("IIII IC xiv"), None);
+        assert_eq!(Scanner::part_1(Tokens::Extended).calibration("XIV 12 twenty 345"), Some(12345));
+        // the same words as digits
+        assert_eq!(matcher().calibration("seventeen and 3"), Some(73));
+        assert_eq!(matcher().calibration("x42y"), Some(42));
+
+        let czech = Vocabulary::preset("czech").unwrap().part_2_scanner(Tokens::Extended);
+        assert_eq!(czech.calibration("dvacetjedna a osmnáct"), Some(2118));
+        let german = Vocabulary::preset("german").unwrap().part_2_scanner(Tokens::Extended);
+        assert_eq!(german.calibration("zwölf und einundzwanzig"), Some(1220));
+    }
+
+    #[test]
+    fn roman_test() {
+        assert_eq!(roman("XIV"), Some(14));
+        assert_eq!(roman("MCMXC"), Some(1990));
+        assert_eq!(roman("MMMCMXCIX"), Some(3999));
+        assert_eq!(roman("IIII"), None);
+        assert_eq!(roman("IC"), None);
+        assert_eq!(roman("VX"), None);
+        assert_eq!(roman("MMMM"), None);
+    }
+
+    #[test]
+    fn concatenate_test() {
+        assert_eq!(concatenate(1, 2), 12);
+        assert_eq!(concatenate(3, 0), 30);
+        assert_eq!(concatenate(12, 345), 12345);
+        assert_eq!(concatenate(0, 7), 7);
+        assert_eq!(concatenate(999_999_999, 999_999_999), 999_999_999_999_999_999);
+        assert_eq!("extended".parse(), Ok(Tokens::Extended));
+        assert!("roman".parse::<Tokens>().is_err());
+    }
+
+    #[test]
+    fn digitless_test() {
+        let lines = vec!["two1nine".to_string(), "eightwothree".to_string()];
+        let error = first_digitless(&lines, &Options::default(), Part::One).unwrap_err();
+        assert_eq!((error.line, error.message.as_str()), (2, "nothing to calibrate for part 1"));
+        assert!(first_digitless(&lines, &Options::default(), Part::Two).is_ok());
+        let input = Day1::parse("two1nine\neightwothree").unwrap();
+        assert_eq!((Day1::part_1(&input), Day1::part_2(&input)), (11, 112));
+        assert!(Day1::check(&input, Part::One).is_ok());
+
+        // the settings of the run reach the parsed input and the streamed state
+        let mut settings = Settings::default();
+        settings.set(DIGITLESS, Digitless::Error);
+        let input = Day1::parse_with("two1nine\neightwothree", &settings).unwrap();
+        assert_eq!(Day1::check(&input, Part::One).unwrap_err().line, 2);
+        assert!(Day1::check(&input, Part::Two).is_ok());
+        assert_eq!(Day1::start(&settings).digitless, Digitless::Error);
+
+        // only the part without a calibration fails, and the first line of all chunks counts
+        let mut state = Calibration { digitless: Digitless::Error, ..Calibration::default() };
+        for (index, text) in ["x", "two1nine", "y"].into_iter().enumerate() {
+            Day1::feed(&mut state, Line { number: index + 1, text }).unwrap();
+        }
+        let mut next = Calibration { digitless: Digitless::Error, ..Calibration::default() };
+        Day1::feed(&mut next, Line { number: 4, text: "seven" }).unwrap();
+        Day1::merge(&mut state, next);
+        assert_eq!(Day1::check_state(&state, Part::One).unwrap_err().line, 1);
+        assert_eq!(Day1::check_state(&state, Part::Two).unwrap_err().line, 1);
+        let mut state = Calibration { digitless: Digitless::Error, ..Calibration::default() };
+        Day1::feed(&mut state, Line { number: 1, text: "eightwothree" }).unwrap();
+        assert_eq!(Day1::check_state(&state, Part::One).unwrap_err().message, "nothing to calibrate for part 1");
+        assert!(Day1::check_state(&state, Part::Two).is_ok());
+        assert_eq!(Day1::finish(state), (0, 83));
+
+        // a panic before, the second example has a line without digits
+        let input = Day1::parse(include_str!("../examples/day01/example2.txt")).unwrap();
+        assert_eq!((Day1::part_1(&input), Day1::part_2(&input)), (209, 281));
+        assert_eq!("skip".parse(), Ok(Digitless::Skip));
+        assert!("ignore".parse::<Digitless>().is_err());
+    }
+
+    #[test]
+    fn explain_test() {
+        let _colors = crate::colors(false);
+        let lines = vec!["two1nine".to_string(), "eightwothree".to_string()];
+        assert_eq!(explain(&lines, &Options::default()), "11 29  two1nine\n 0 83  eightwothree\n");
+        let skip = Options { digitless: Digitless::Skip, ..Options::default() };
+        assert_eq!(explain(&lines, &skip), "11 29  two1nine\n - 83  eightwothree\n");
+    }
+
+    /// Tries every pattern at every char boundary.
+    fn naive(line: &str) -> Option<u64> {
+        let values: Vec<u32> = line
+            .char_indices()
+            .filter_map(|(index, _)| {
+                let words = Vocabulary::default().words.into_iter().filter(|(_, value)| *value <= 9);
+                let mut patterns = DIGITS.iter().map(|&(digit, value)| (digit.to_string(), value)).chain(words);
+                patterns.find(|(pattern, _)| line[index..].starts_with(pattern.as_str())).map(|(_, value)| value)
+            })
+            .collect();
+        Some(*values.first()? as u64 * 10 + *values.last()? as u64)
+    }
+
+    fn line() -> impl Strategy<Value=String> {
+        let piece = prop_oneof![
+            prop::sample::select(Vocabulary::default().words.into_iter().map(|(word, _)| word).collect::<Vec<_>>()),
+            "[a-z0-9]{1,3}",
+            "[ěščřžýáíé✓]",
+        ];
+        prop::collection::vec(piece, 0..8).prop_map(|pieces| pieces.concat())
+    }
+
+    proptest! {
+        #[test]
+        fn agrees_with_naive_scan(line in line()) {
+            prop_assert_eq!(matcher().calibration(&line), naive(&line));
+        }
+
+        #[test]
+        fn ends_agree_with_full_scan(line in line()) {
+            for scanner in [matcher(), Vocabulary::preset("czech").unwrap().part_2_scanner(Tokens::Digits)] {
+                prop_assert_eq!(scanner.first_and_last(&line), first_and_last(scanner.matcher.tokens(&line)));
+            }
+        }
+    }
+}
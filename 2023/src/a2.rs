@@ -0,0 +1,160 @@
+use std::cmp::max;
+use std::str::FromStr;
+
+use crate::parse::{lines, Line, ParseError};
+use crate::stream::Streaming;
+use crate::Solution;
+
+pub struct Day2;
+
+enum Color {
+    Red,
+    Green,
+    Blue,
+}
+
+impl FromStr for Color {
+    type Err = String;
+
+    fn from_str(inp: &str) -> Result<Self, Self::Err> {
+        match inp {
+            "red" => Ok(Self::Red),
+            "green" => Ok(Self::Green),
+            "blue" => Ok(Self::Blue),
+            _ => Err(format!("unknown color {:?}", inp)),
+        }
+    }
+}
+
+#[derive(Debug, Default, PartialEq)]
+pub struct CubeSet {
+    red: u32,
+    green: u32,
+    blue: u32,
+}
+
+impl CubeSet {
+    fn is_possible(&self) -> bool {
+        self.red <= 12 && self.green <= 13 && self.blue <= 14
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Game {
+    id: u32,
+    sets: Vec<CubeSet>,
+    power: u64,
+}
+
+impl Game {
+    fn is_possible(&self) -> bool {
+        self.sets.iter().all(CubeSet::is_possible)
+    }
+}
+
+/// The product of the fewest cubes of each color, `None` when it overflows.
+fn power(sets: &[CubeSet]) -> Option<u64> {
+    let mut max_r = 0;
+    let mut max_g = 0;
+    let mut max_b = 0;
+    for set in sets.iter() {
+        max_r = max(set.red, max_r);
+        max_g = max(set.green, max_g);
+        max_b = max(set.blue, max_b);
+    }
+    u64::from(max_r).checked_mul(u64::from(max_g))?.checked_mul(u64::from(max_b))
+}
+
+fn parse_set<'a>(line: &Line<'a>, raw_set: &'a str) -> Result<CubeSet, ParseError> {
+    let mut set = CubeSet::default();
+    for raw_cubes in raw_set.split(", ") {
+        // raw_cubes = "14 red"
+        let (num_str, color_str) = line.split_once(raw_cubes, " ")?;
+        let num = line.number(num_str)?;
+        match color_str.parse().map_err(|err: String| line.error(color_str, err))? {
+            Color::Red => set.red = num,
+            Color::Green => set.green = num,
+            Color::Blue => set.blue = num,
+        };
+    }
+    Ok(set)
+}
+
+fn get_raw_sets<'a>(line: &Line<'a>) -> Result<(u32, &'a str), ParseError> {
+    let (game_id, raw_sets) = line.split_once(line.text, ": ")?;
+    let (_, id_s) = line.split_once(game_id, " ")?;
+    let id = line.number(id_s)?;
+    Ok((id, raw_sets))
+}
+
+fn parse_game(line: Line) -> Result<Game, ParseError> {
+    let (id, raw_sets) = get_raw_sets(&line)?;
+    // raw_set = "14 red, 12 blue"
+    let sets: Vec<CubeSet> = raw_sets.split("; ").map(|raw_set| parse_set(&line, raw_set)).collect::<Result<_, _>>()?;
+    let power = power(&sets).ok_or_else(|| line.error(raw_sets, "the power of the game overflows"))?;
+    Ok(Game { id, sets, power })
+}
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+
+    type Input = Vec<Game>;
+    type Answer1 = u64;
+    type Answer2 = u128;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        lines(input).map(parse_game).collect()
+    }
+
+    fn part_1(games: &Self::Input) -> u64 {
+        games.iter().filter(|game| game.is_possible()).map(|game| u64::from(game.id)).sum()
+    }
+
+    fn part_2(games: &Self::Input) -> u128 {
+        games.iter().map(|game| u128::from(game.power)).sum()
+    }
+}
+
+impl Streaming for Day2 {
+    type State = (u64, u128);
+
+    fn feed(answers: &mut Self::State, line: Line) -> Result<(), ParseError> {
+        let game = parse_game(line)?;
+        if game.is_possible() {
+            answers.0 += u64::from(game.id);
+        }
+        answers.1 += u128::from(game.power);
+        Ok(())
+    }
+
+    fn finish(answers: Self::State) -> (u64, u128) {
+        answers
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::Solution;
+    use super::Day2;
+
+    #[test]
+    fn parse_test() {
+        let games = Day2::parse(include_str!("../examples/day02/example.txt")).unwrap();
+        assert_eq!(games.len(), 5);
+        assert_eq!(games[2].power, 1560);
+        assert!(!games[2].is_possible());
+    }
+
+    #[test]
+    fn parse_error_test() {
+        let error = Day2::parse("Game 1: 3 blue, 4 red\nGame 2: 1 blue, 2 purple; 3 green").unwrap_err();
+        assert_eq!((error.line, error.column, error.snippet.as_str()), (2, 19, "purple"));
+        let error = Day2::parse("Game 1 3 blue").unwrap_err();
+        assert_eq!((error.line, error.column, error.snippet.as_str()), (1, 1, "Game 1 3 blue"));
+        let error = Day2::parse("Game 1: 4294967295 red, 4294967295 green, 4294967295 blue").unwrap_err();
+        assert_eq!((error.line, error.column, error.message.as_str()), (1, 9, "the power of the game overflows"));
+        let games = Day2::parse("Game 4294967295: 1 red\nGame 4294967295: 1 red").unwrap();
+        assert_eq!(Day2::part_1(&games), 2 * 4294967295);
+    }
+}
@@ -0,0 +1,239 @@
+use std::collections::HashSet;
+
+use crate::parse::{lines, Line as InputLine, ParseError};
+use crate::render::{Grid, Style};
+use crate::Solution;
+
+pub struct Day3;
+
+#[derive(Default, Debug, Clone, PartialEq)]
+struct Number {
+    value: u32,
+    digit_counts: usize,
+    end_index: usize,
+    /// The line of the schematic, 0-based.
+    row: usize,
+}
+
+impl Number {
+    fn start_index(&self) -> usize {
+        self.end_index + 1 - self.digit_counts
+    }
+
+    /// Row and column, only one number starts there.
+    fn position(&self) -> (usize, usize) {
+        (self.row, self.start_index())
+    }
+}
+
+#[derive(Debug)]
+struct Symbol {
+    value: char,
+    index: usize,
+}
+
+#[derive(Debug, Clone)]
+struct Gear {
+    index: usize,
+}
+
+#[derive(Default)]
+pub struct Line {
+    numbers: Vec<Number>,
+    symbols: Vec<Symbol>,
+    gears: Vec<Gear>,
+    /// In characters.
+    width: usize,
+}
+
+struct GearParts(Number, Number);
+
+impl GearParts {
+    fn value(&self) -> u64 {
+        u64::from(self.0.value) * u64::from(self.1.value)
+    }
+}
+
+fn visualize(lines: &[Line]) -> String {
+    let parts: HashSet<(usize, usize)> = get_valid_part_numbers(lines).iter().map(Number::position).collect();
+    let gear_parts: HashSet<(usize, usize)> =
+        find_gear_parts(lines).iter().flat_map(|GearParts(a, b)| [a.position(), b.position()]).collect();
+    let width = lines.iter().map(|line| line.width).max().unwrap_or(0);
+    let mut grid = Grid::new(lines.len(), width, '.');
+    for (row, line) in lines.iter().enumerate() {
+        grid.style(row, 0..width, Style::Dim);
+        for number in line.numbers.iter() {
+            let style = if gear_parts.contains(&number.position()) {
+                Style::Alert
+            } else if parts.contains(&number.position()) {
+                Style::Accent
+            } else {
+                Style::Plain
+            };
+            grid.put(row, number.start_index(), &number.value.to_string(), style);
+        }
+        for symbol in line.symbols.iter() {
+            let is_gear = line.gears.iter().any(|gear| gear.index == symbol.index);
+            grid.put(row, symbol.index, &symbol.value.to_string(), if is_gear { Style::Alert } else { Style::Plain });
+        }
+    }
+    grid.render()
+}
+
+
+fn parse_line(line: InputLine) -> Result<Line, ParseError> {
+    let mut numbers: Vec<Number> = vec![];
+    let mut symbols: Vec<Symbol> = vec![];
+    let mut gears: Vec<Gear> = vec![];
+    let mut digits: Option<(usize, usize)> = None;  // byte offset and index of the first digit
+    // the trailing '.' ends a number at the end of the line
+    for (index, (offset, char)) in line.text.char_indices().chain([(line.text.len(), '.')]).enumerate() {
+        if char.is_ascii_digit() {
+            digits.get_or_insert((offset, index));
+            continue;
+        }
+        if let Some((start, start_index)) = digits.take() {
+            let value: u32 = line.number(&line.text[start..offset])?;
+            if value > 0 {
+                numbers.push(Number { value, digit_counts: index - start_index, end_index: index - 1, row: line.number - 1 });
+            }
+        }
+        if char != '.' {
+            symbols.push(Symbol { index, value: char })
+        }
+        if char == '*' {
+            gears.push(Gear { index })
+        }
+    }
+    Ok(Line { numbers, symbols, gears, width: line.text.chars().count() })
+}
+
+fn is_neighbor(symbol_index: usize, number: &Number) -> bool {
+    symbol_index <= (number.end_index + 1) && (symbol_index as i32) >= ((number.start_index() as i32) - 1)
+}
+
+/// The line itself together with the lines directly above and below it.
+fn neighborhood(lines: &[Line], index: usize) -> &[Line] {
+    &lines[index.saturating_sub(1)..(index + 2).min(lines.len())]
+}
+
+fn get_valid_part_numbers(lines: &[Line]) -> Vec<Number> {
+    let mut parts: Vec<Number> = vec![];
+    for (line_index, line) in lines.iter().enumerate() {
+        let neighbors = neighborhood(lines, line_index);
+        for number in line.numbers.iter() {
+            let is_part = neighbors
+                .iter()
+                .flat_map(|neighbor| neighbor.symbols.iter())
+                .any(|symbol| is_neighbor(symbol.index, number));
+            if is_part {
+                parts.push(number.clone());
+            }
+        }
+    }
+    parts
+}
+
+fn find_gear_parts(lines: &[Line]) -> Vec<GearParts> {
+    let mut gear_parts: Vec<GearParts> = vec![];
+    for (line_index, line) in lines.iter().enumerate() {
+        let neighbors = neighborhood(lines, line_index);
+        for gear in line.gears.iter() {
+            let neighbor_numbers: Vec<&Number> = neighbors
+                .iter()
+                .flat_map(|neighbor| neighbor.numbers.iter())
+                .filter(|number| is_neighbor(gear.index, number))
+                .collect();
+            if let [first, second] = neighbor_numbers[..] {
+                gear_parts.push(GearParts(first.clone(), second.clone()));
+            }
+        }
+    }
+    gear_parts
+}
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+
+    type Input = Vec<Line>;
+    // part numbers of nine digits add up quickly, and gear ratios multiply two of them
+    type Answer1 = u64;
+    type Answer2 = u128;
+
+    fn parse(schematic: &str) -> Result<Self::Input, ParseError> {
+        lines(schematic).map(parse_line).collect()
+    }
+
+    fn part_1(lines: &Self::Input) -> u64 {
+        get_valid_part_numbers(lines).iter().map(|x| u64::from(x.value)).sum()
+    }
+
+    fn part_2(lines: &Self::Input) -> u128 {
+        find_gear_parts(lines).iter().map(|x| u128::from(x.value())).sum()
+    }
+
+    fn visualize(lines: &Self::Input) -> Option<String> {
+        Some(visualize(lines))
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::render::Style;
+    use crate::Solution;
+    use super::{find_gear_parts, get_valid_part_numbers, visualize, Day3};
+
+    #[test]
+    fn parts_test() {
+        let lines = Day3::parse(include_str!("../examples/day03/example.txt")).unwrap();
+        let parts: Vec<u32> = get_valid_part_numbers(&lines).iter().map(|number| number.value).collect();
+        assert_eq!(parts, vec![467, 35, 633, 617, 592, 755, 664, 598]);
+        let gears: Vec<u64> = find_gear_parts(&lines).iter().map(|gear_parts| gear_parts.value()).collect();
+        assert_eq!(gears, vec![16345, 451490]);
+    }
+
+    #[test]
+    fn visualize_test() {
+        let _colors = crate::colors(false);
+        let input = include_str!("../examples/day03/example.txt");
+        assert_eq!(visualize(&Day3::parse(input).unwrap()), input);
+    }
+
+    #[test]
+    fn colored_visualize_test() {
+        let _colors = crate::colors(true);
+        // the same 12 in another row is neither a part nor next to a gear
+        let input = "12*3\n....\n12..";
+        let expected = [
+            Style::Alert.paint("12*3").to_string(),
+            Style::Dim.paint("....").to_string(),
+            Style::Plain.paint("12").to_string() + &Style::Dim.paint("..").to_string(),
+        ];
+        assert!(expected[0].starts_with('\x1b'));
+        assert_eq!(visualize(&Day3::parse(input).unwrap()), expected.join("\n") + "\n");
+    }
+
+    #[test]
+    fn symbols_above_and_below_test() {
+        // 123 counted once for each line with a symbol next to it before, part 1 was 702
+        let lines = Day3::parse("..#..\n.123.\n..$..\n.....\n+456*").unwrap();
+        let parts: Vec<u32> = get_valid_part_numbers(&lines).iter().map(|number| number.value).collect();
+        assert_eq!(parts, vec![123, 456]);
+        assert_eq!(Day3::part_1(&lines), 579);
+    }
+
+    #[test]
+    fn large_numbers_test() {
+        // a panic before, the product and the sum overflowed u32
+        let lines = Day3::parse("99999*99999\n\n4000000000*4000000000").unwrap();
+        assert_eq!(Day3::part_1(&lines), 8_000_199_998);
+        assert_eq!(Day3::part_2(&lines), 9_999_800_001 + 16_000_000_000_000_000_000);
+    }
+
+    #[test]
+    fn parse_error_test() {
+        let error = Day3::parse("467..114..\n...*..99999999999.").err().unwrap();
+        assert_eq!((error.line, error.column, error.snippet.as_str()), (2, 7, "99999999999"));
+    }
+}
@@ -0,0 +1,194 @@
+use std::collections::{HashSet, VecDeque};
+
+use crate::parse::{lines, Line as InputLine, ParseError};
+use crate::registry::Part;
+use crate::stream::Streaming;
+use crate::Solution;
+
+pub struct Day4;
+
+/// The most winning numbers of a card, so its points fit a u64.
+const MAX_WINNING: usize = 64;
+
+pub struct Line {
+    winning_numbers: Vec<usize>,
+    numbers: Vec<usize>,
+}
+
+fn parse_line(line: InputLine) -> Result<Line, ParseError> {
+    let (_, numbers_str) = line.split_once(line.text, ":")?;
+    let (winning_numbers_str, numbers_str) = line.split_once(numbers_str, "|")?;
+    let winning_numbers: Vec<usize> = line.numbers(winning_numbers_str)?;
+    if winning_numbers.len() > MAX_WINNING {
+        let message = format!("a card can have at most {} winning numbers, not {}", MAX_WINNING, winning_numbers.len());
+        return Err(line.error(winning_numbers_str, message));
+    }
+    let numbers = line.numbers(numbers_str)?;
+
+    Ok(Line {
+        winning_numbers,
+        numbers,
+    })
+}
+
+fn wins_for_line(line: &Line) -> usize {
+    let numbers = line.numbers.iter().collect::<HashSet<_>>();
+    let winning_numbers = line.winning_numbers.iter().collect::<HashSet<_>>();
+    let intersection = numbers.intersection(&winning_numbers).collect::<Vec<_>>();
+    intersection.len()
+}
+
+fn points_for_line(line: &Line) -> u64 {
+    let wins = wins_for_line(line);
+    if wins > 0 {
+        1 << (wins - 1)
+    } else {
+        0
+    }
+}
+
+/// Copies of the next card, including the original, passing its `wins` on to the cards after it.
+/// `next_cards` never holds more than the most wins of a card. `None` once a count overflows,
+/// they can double with every card.
+fn copies_of_card(next_cards: &mut VecDeque<u128>, wins: usize) -> Option<u128> {
+    let number_of_copies = next_cards.pop_front().unwrap_or(0).checked_add(1)?;
+    for next_card in next_cards.iter_mut().take(wins) {
+        *next_card = next_card.checked_add(number_of_copies)?;
+    }
+    for _ in next_cards.len()..wins {
+        next_cards.push_back(number_of_copies);
+    }
+    Some(number_of_copies)
+}
+
+/// All the cards in the end, `None` when they overflow.
+fn total_cards(lines: &[Line]) -> Option<u128> {
+    let mut next_cards = VecDeque::new();
+    lines.iter().try_fold(0u128, |total, line| total.checked_add(copies_of_card(&mut next_cards, wins_for_line(line))?))
+}
+
+fn too_many_cards(lines: usize) -> ParseError {
+    ParseError::end_of_input(lines + 1, "the copies of the cards overflow")
+}
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+
+    type Input = Vec<Line>;
+    type Answer1 = u128;
+    type Answer2 = u128;
+
+    fn parse(cards: &str) -> Result<Self::Input, ParseError> {
+        lines(cards).map(parse_line).collect()
+    }
+
+    fn check(lines: &Self::Input, part: Part) -> Result<(), ParseError> {
+        match part {
+            Part::Two if total_cards(lines).is_none() => Err(too_many_cards(lines.len())),
+            _ => Ok(()),
+        }
+    }
+
+    fn part_1(lines: &Self::Input) -> u128 {
+        lines.iter().map(|line| u128::from(points_for_line(line))).sum()
+    }
+
+    fn part_2(lines: &Self::Input) -> u128 {
+        // Day4::check already counted them without overflowing
+        total_cards(lines).unwrap()
+    }
+}
+
+/// Points so far, cards so far and the copies won for the cards ahead, the cards `None` once
+/// they overflow.
+pub struct Tally {
+    points: u128,
+    cards: Option<u128>,
+    next_cards: VecDeque<u128>,
+    lines: usize,
+}
+
+impl Default for Tally {
+    fn default() -> Self {
+        Tally { points: 0, cards: Some(0), next_cards: VecDeque::new(), lines: 0 }
+    }
+}
+
+impl Streaming for Day4 {
+    type State = Tally;
+
+    fn feed(tally: &mut Tally, line: InputLine) -> Result<(), ParseError> {
+        let line = parse_line(line)?;
+        tally.points += u128::from(points_for_line(&line));
+        if let Some(cards) = tally.cards {
+            tally.cards = copies_of_card(&mut tally.next_cards, wins_for_line(&line)).and_then(|copies| cards.checked_add(copies));
+        }
+        tally.lines += 1;
+        Ok(())
+    }
+
+    fn check_state(tally: &Tally, part: Part) -> Result<(), ParseError> {
+        match part {
+            Part::Two if tally.cards.is_none() => Err(too_many_cards(tally.lines)),
+            _ => Ok(()),
+        }
+    }
+
+    fn finish(tally: Tally) -> (u128, u128) {
+        (tally.points, tally.cards.unwrap_or_default())
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::fmt::Write;
+
+    use aoc_shared::answers::InputHasher;
+    use crate::Solution;
+    use crate::parse::lines;
+    use crate::registry::Part;
+    use crate::settings::Settings;
+    use crate::stream::{stream, StreamError};
+    use super::{parse_line, points_for_line, Day4, MAX_WINNING};
+
+    const CARDS: &str = include_str!("../examples/day04/example.txt");
+
+    #[test]
+    fn test_points_for_line() {
+        for (input_line, points) in lines(CARDS).zip([8, 2, 2, 1, 0, 0]) {
+            let line = parse_line(input_line).unwrap();
+            assert_eq!(points_for_line(&line), points);
+        }
+    }
+
+    #[test]
+    fn many_wins_test() {
+        // a panic before, 2 to the 64 points and copies doubling with every card
+        let winning: Vec<String> = (1..=MAX_WINNING).map(|number| number.to_string()).collect();
+        let winning = winning.join(" ");
+        let mut cards = String::new();
+        for card in 1..=140 {
+            writeln!(cards, "Card {}: {} | {}", card, winning, winning).unwrap();
+        }
+        let input = Day4::parse(&cards).unwrap();
+        assert_eq!(Day4::part_1(&input), 140 << 63);
+        let error = Day4::check(&input, Part::Two).unwrap_err();
+        assert_eq!((error.line, error.message.as_str()), (141, "the copies of the cards overflow"));
+        match stream::<Day4>(&mut cards.as_bytes(), &mut InputHasher::default(), &Settings::default(), &[Part::Two]) {
+            Err(StreamError::Parse(error)) => assert_eq!(error.line, 141),
+            other => panic!("unexpected {:?}", other.map(|_| ())),
+        }
+        let answers = stream::<Day4>(&mut cards.as_bytes(), &mut InputHasher::default(), &Settings::default(), &[Part::One]).unwrap();
+        assert_eq!(answers.0, 140 << 63);
+
+        let error = Day4::parse(&format!("Card 1: {} 65 | 1", winning)).err().unwrap();
+        assert_eq!(error.message, "a card can have at most 64 winning numbers, not 65");
+    }
+
+    #[test]
+    fn parse_error_test() {
+        let error = Day4::parse("Card 1: 41 48 | 83 86\nCard 2: 13 32 | 61 3O").err().unwrap();
+        assert_eq!((error.line, error.column, error.snippet.as_str()), (2, 20, "3O"));
+    }
+}
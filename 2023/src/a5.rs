@@ -0,0 +1,298 @@
+use std::cmp::{max, min};
+use itertools::Itertools;
+
+use crate::parse::{lines, ParseError};
+use crate::Solution;
+
+pub struct Day5;
+
+#[derive(Debug, Clone)]
+struct Mapping {
+    submappings: Vec<Submapping>,
+}
+
+impl Mapping {
+    fn get_dest_from_source(&self, source: i64) -> i64 {
+        let mut s: i64 = source;
+        for submapping in self.submappings.iter() {
+            s = submapping.get_dest_from_source(s);
+        }
+        s
+    }
+}
+
+
+type Seeds = Vec<i64>;
+
+type SeedRanges = Vec<SeedRange>;
+
+#[derive(Clone)]
+struct SeedRange {
+    start: i64,
+    end: i64,
+}
+
+impl SeedRange {
+    fn new(start: i64, range: i64) -> Option<Self> {
+        Some(Self { start, end: range_end(start, range)? })
+    }
+}
+
+/// The last of `range` numbers from `start`, `None` when it or the number after it overflows.
+fn range_end(start: i64, range: i64) -> Option<i64> {
+    start.checked_add(range)?.checked_sub(1)
+}
+
+#[derive(Debug, Clone)]
+struct Submapping {
+    maps: Vec<SourceDestMap>,
+}
+
+impl Submapping {
+    fn get_dest_from_source(&self, source: i64) -> i64 {
+        for map in self.maps.iter() {
+            if let Some(dest) = map.get_dest_from_source(source) {
+                return dest;
+            }
+        }
+        source
+    }
+}
+
+#[derive(Debug, Clone)]
+struct SourceDestMap {
+    source_start: i64,
+    source_end: i64,
+    destination_diff: i64,
+}
+
+
+impl SourceDestMap {
+    /// `None` when a range overflows, then mapping could overflow too.
+    fn new(destination_start: i64, source_start: i64, range: i64) -> Option<Self> {
+        range_end(destination_start, range)?;
+        Some(Self {
+            source_start,
+            source_end: range_end(source_start, range)?,
+            destination_diff: destination_start.checked_sub(source_start)?,
+        })
+    }
+
+    fn get_dest_from_source(&self, source: i64) -> Option<i64> {
+        if source >= self.source_start && source <= self.source_end {
+            Some(source + self.destination_diff)
+        } else {
+            None
+        }
+    }
+}
+
+pub struct Almanac {
+    seeds: Seeds,
+    seed_ranges: SeedRanges,
+    mapping: Mapping,
+}
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+
+    type Input = Almanac;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut lines = lines(input);
+        let first_line = lines.next().ok_or_else(|| ParseError::end_of_input(1, "seeds must be here"))?;
+        let (_, seeds_str) = first_line.split_once(first_line.text, ": ")?;
+        let seeds: Seeds = first_line.numbers(seeds_str)?;
+        if seeds.is_empty() {
+            return Err(first_line.error(seeds_str, "seeds must not be empty"));
+        }
+        if !seeds.len().is_multiple_of(2) {
+            return Err(first_line.error(seeds_str, "seeds must come in pairs of start and range"));
+        }
+        let mut seed_ranges = vec![];
+        for pair in seeds.chunks(2) {
+            let seed_range = SeedRange::new(pair[0], pair[1]);
+            seed_ranges.push(seed_range.ok_or_else(|| first_line.error(seeds_str, format!("seeds {} {} overflow", pair[0], pair[1])))?);
+        }
+        let _ = lines.next();  // empty line
+        let mut mapping: Mapping = Mapping { submappings: vec![] };
+        while lines.next().is_some() {  // map description
+            let mut submapping = Submapping { maps: vec![] };
+            for line in lines.by_ref() {
+                if line.text.is_empty() {
+                    break;
+                }
+                let (destination_start, source_start, range_length) = line.numbers(line.text)?
+                    .into_iter()
+                    .collect_tuple()
+                    .ok_or_else(|| line.error(line.text, "expected destination start, source start and range length"))?;
+                let map = SourceDestMap::new(destination_start, source_start, range_length);
+                submapping.maps.push(map.ok_or_else(|| line.error(line.text, "the ranges overflow"))?);
+            }
+
+            mapping.submappings.push(submapping);
+        }
+        Ok(Almanac { seeds, seed_ranges, mapping })
+    }
+
+    fn part_1(almanac: &Self::Input) -> i64 {
+        almanac.seeds.iter().map(|x| almanac.mapping.get_dest_from_source(*x)).min().unwrap()
+    }
+
+    fn part_2(almanac: &Self::Input) -> i64 {
+        let mut seed_ranges = almanac.seed_ranges.clone();
+        let mut mapping = almanac.mapping.clone();
+
+        for submapping in mapping.submappings.iter_mut() {
+            seed_ranges.sort_by_key(|x| x.start);
+            // the sweep below needs disjoint ranges, but mapped ranges can overlap
+            seed_ranges = seed_ranges
+                .into_iter()
+                .coalesce(|a, b| if b.start <= a.end + 1 {
+                    Ok(SeedRange { start: a.start, end: max(a.end, b.end) })
+                } else {
+                    Err((a, b))
+                })
+                .collect();
+            let mut seeds = seed_ranges.iter_mut();
+            let mut new_seeds: Vec<SeedRange> = vec![];
+            submapping.maps.sort_by_key(|x| x.source_start);
+            let mut maps = submapping.maps.iter();
+
+            let mut maybe_seed = seeds.next();
+            let mut maybe_sourcedestmap = maps.next();
+            while let Some(ref mut seed) = maybe_seed {
+                if let Some(sourcedestmap) = maybe_sourcedestmap {
+                    if seed.start <= sourcedestmap.source_end { // DS <= CE
+                        if seed.end < sourcedestmap.source_start {  // DE < CS
+                            new_seeds.push(SeedRange { start: seed.start, end: seed.end }); // DS DE
+                        } else {
+                            if seed.start < sourcedestmap.source_start { // DS < CS
+                                new_seeds.push(SeedRange { start: seed.start, end: sourcedestmap.source_start - 1 }); // DS CS-1
+                            }
+                            new_seeds.push(SeedRange {
+                                start: max(seed.start, sourcedestmap.source_start) + sourcedestmap.destination_diff,
+                                end: min(seed.end, sourcedestmap.source_end) + sourcedestmap.destination_diff,
+                            });
+                        }
+
+                        if sourcedestmap.source_end < seed.end { // CE < DE
+                            seed.start = sourcedestmap.source_end + 1;
+                            maybe_sourcedestmap = maps.next()
+                        } else {
+                            maybe_seed = seeds.next();
+                        }
+                    } else {
+                        maybe_sourcedestmap = maps.next()
+                    }
+                } else {
+                    new_seeds.push(SeedRange { start: seed.start, end: seed.end });
+                    maybe_seed = seeds.next();
+                }
+            }
+            seed_ranges = new_seeds;
+        }
+
+        seed_ranges.iter().map(|x| x.start).min().unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use crate::Solution;
+    use super::Day5;
+
+    const INPUT: &str = include_str!("../examples/day05/example.txt");
+
+    #[test]
+    fn parse_test() {
+        let almanac = Day5::parse(INPUT).unwrap();
+        assert_eq!(almanac.seeds, vec![79, 14, 55, 13]);
+        assert_eq!(almanac.mapping.submappings.len(), 7);
+        assert_eq!(almanac.mapping.get_dest_from_source(79), 82);
+    }
+
+    #[test]
+    fn parse_error_test() {
+        let error = Day5::parse("").err().unwrap();
+        assert_eq!((error.line, error.message.as_str()), (1, "seeds must be here"));
+        let error = Day5::parse("seeds: 79 14 55").err().unwrap();
+        assert_eq!((error.line, error.column), (1, 8));
+        let error = Day5::parse(&INPUT.replace("0 15 37", "0 15")).err().unwrap();
+        assert_eq!((error.line, error.column, error.snippet.as_str()), (8, 1, "0 15"));
+        let error = Day5::parse("seeds: \n\na map:\n1 2 3").err().unwrap();
+        assert_eq!((error.line, error.message.as_str()), (1, "seeds must not be empty"));
+        let error = Day5::parse("seeds: 9223372036854775807 2").err().unwrap();
+        assert_eq!((error.line, error.message.as_str()), (1, "seeds 9223372036854775807 2 overflow"));
+        let error = Day5::parse("seeds: 1 1\n\na map:\n-9223372036854775808 9223372036854775807 1").err().unwrap();
+        assert_eq!((error.line, error.message.as_str()), (4, "the ranges overflow"));
+        assert!(Day5::parse("seeds: 1 1\n\na map:\n9223372036854775806 0 1").is_ok());
+    }
+
+    #[test]
+    fn part_2_overlapping_ranges_test() {
+        // 22 to 30 moves the sweep past the map before 25 to 25 gets to it
+        let almanac = Day5::parse("seeds: 25 1 22 9\n\na map:\n15 11 15").unwrap();
+        assert_eq!(Day5::part_2(&almanac), 26);
+    }
+
+    #[test]
+    fn part_2_range_starting_before_map_test() {
+        // 14 is mapped by the first map, only 11 to 13 go through unchanged
+        let almanac = Day5::parse("seeds: 11 4\n\na map:\n3 14 1\n\nb map:\n17 14 1\n\nc map:\n12 0 14").unwrap();
+        assert_eq!(Day5::part_2(&almanac), 15);
+    }
+
+    /// Lines "destination source length" of one map, with sources not overlapping.
+    fn map_lines() -> impl Strategy<Value=Vec<String>> {
+        prop::collection::vec((0..20i64, 1..20i64, 0..150i64), 0..5)
+            .prop_map(|ranges| {
+                let mut source = 0;
+                ranges
+                    .into_iter()
+                    .map(|(gap, length, destination)| {
+                        source += gap;
+                        let line = format!("{} {} {}", destination, source, length);
+                        source += length;
+                        line
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .prop_shuffle()
+    }
+
+    fn almanac() -> impl Strategy<Value=String> {
+        (prop::collection::vec((0..100i64, 1..10i64), 1..4), prop::collection::vec(map_lines(), 1..5)).prop_map(
+            |(seeds, maps)| {
+                let seeds: Vec<_> = seeds.iter().map(|(start, length)| format!("{} {}", start, length)).collect();
+                let maps: Vec<_> = maps
+                    .iter()
+                    .enumerate()
+                    .map(|(index, lines)| {
+                        let header = format!("map{}-to-map{} map:", index, index + 1);
+                        [vec![header], lines.clone()].concat().join("\n")
+                    })
+                    .collect();
+                format!("seeds: {}\n\n{}", seeds.join(" "), maps.join("\n\n"))
+            }
+        )
+    }
+
+    proptest! {
+        #[test]
+        fn part_2_maps_ranges_like_every_seed(input in almanac()) {
+            let almanac = Day5::parse(&input).unwrap();
+            let naive = almanac.seed_ranges
+                .iter()
+                .flat_map(|range| range.start..=range.end)
+                .map(|seed| almanac.mapping.get_dest_from_source(seed))
+                .min()
+                .unwrap();
+            prop_assert_eq!(Day5::part_2(&almanac), naive);
+        }
+    }
+}
@@ -0,0 +1,109 @@
+use crate::parse::{lines, ParseError};
+use crate::Solution;
+
+pub struct Day6;
+
+#[derive(PartialEq, Debug)]
+pub struct Race {
+    time: u64,
+    distance: u64,
+}
+
+fn wins(race: &Race, power_time: u64) -> bool {
+    u128::from(race.time - power_time) * u128::from(power_time) > u128::from(race.distance)
+}
+
+/// Distances rise up to half the time and fall symmetrically, so the first win up to there
+/// bounds all of them.
+fn ways_to_win(race: &Race) -> u64 {
+    let (mut low, mut high) = (1, race.time / 2 + 1);
+    while low < high {
+        let middle = low + (high - low) / 2;
+        if wins(race, middle) {
+            high = middle;
+        } else {
+            low = middle + 1;
+        }
+    }
+    if low > race.time / 2 { 0 } else { race.time - 2 * low + 1 }
+}
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+
+    type Input = (Vec<Race>, Race);
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut lines = lines(input);
+        let line_times = lines.next().ok_or_else(|| ParseError::end_of_input(1, "missing times"))?;
+        let line_distances = lines.next().ok_or_else(|| ParseError::end_of_input(2, "missing distances"))?;
+        let (_, times_str) = line_times.split_once(line_times.text, ":")?;
+        let (_, distances_str) = line_distances.split_once(line_distances.text, ":")?;
+        let times: Vec<u64> = line_times.numbers(times_str)?;
+        let distances: Vec<u64> = line_distances.numbers(distances_str)?;
+        if times.len() != distances.len() {
+            return Err(line_distances.error(distances_str, format!("expected {} distances", times.len())));
+        }
+        // each race has fewer ways to win than its time
+        if times.iter().try_fold(1u64, |product, &time| product.checked_mul(time)).is_none() {
+            return Err(line_times.error(times_str, "the times multiply past u64"));
+        }
+
+        let time = times_str.replace(' ', "").parse().map_err(|_| line_times.error(times_str, "time is too long"))?;
+        let distance = distances_str.replace(' ', "").parse().map_err(|_| line_distances.error(distances_str, "distance is too long"))?;
+
+        Ok((
+            std::iter::zip(times, distances).map(|(time, distance)| Race { time, distance }).collect(),
+            Race { time, distance }
+        ))
+    }
+
+    fn part_1((races, _): &Self::Input) -> u64 {
+        races.iter().map(ways_to_win).product()
+    }
+
+    fn part_2((_, race): &Self::Input) -> u64 {
+        ways_to_win(race)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Solution;
+    use super::{ways_to_win, Day6, Race};
+
+    const INPUT: &str = include_str!("../examples/day06/example.txt");
+
+    #[test]
+    fn parse_test() {
+        assert_eq!(Day6::parse(INPUT).unwrap(), (
+            vec![Race { time: 7, distance: 9 }, Race { time: 15, distance: 40 }, Race { time: 30, distance: 200 }],
+            Race { time: 71530, distance: 940200 }
+        )
+        );
+    }
+
+    #[test]
+    fn parse_error_test() {
+        let error = Day6::parse("Time:      7  15   30").unwrap_err();
+        assert_eq!((error.line, error.message.as_str()), (2, "missing distances"));
+        let error = Day6::parse("Time:      7  15   30\nDistance:  9  40").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 10));
+        let error = Day6::parse("Time: 4294967296 4294967296\nDistance: 1 1").unwrap_err();
+        assert_eq!((error.line, error.message.as_str()), (1, "the times multiply past u64"));
+    }
+
+    #[test]
+    fn ways_to_win_test() {
+        let naive = |race: &Race| (1..race.time).filter(|&power_time| (race.time - power_time) * power_time > race.distance).count() as u64;
+        for time in 0..40 {
+            for distance in 0..420 {
+                let race = Race { time, distance };
+                assert_eq!(ways_to_win(&race), naive(&race), "{:?}", race);
+            }
+        }
+        assert_eq!(ways_to_win(&Race { time: u64::MAX, distance: u64::MAX }), u64::MAX - 3);
+    }
+}
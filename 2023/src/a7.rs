@@ -0,0 +1,231 @@
+use std::collections::BTreeMap;
+
+use itertools::Itertools;
+
+use crate::parse::{lines, Line, ParseError};
+use crate::stream::Streaming;
+use crate::Solution;
+
+pub struct Day7;
+
+const CARDS: &str = "23456789TJQKA";
+
+const PART_2_CARDS: &str = "J23456789TQKA";
+
+fn get_kind(hand: &[usize]) -> usize {
+    let binding = hand
+        .iter()
+        .counts();
+
+    let mut kinds: Vec<_> = binding.values().collect();
+    kinds.sort();
+    kinds.reverse();
+
+    match kinds[0] {
+        5 => 6,
+        4 => 5,
+        3 => match kinds[1] {
+            2 => 4,
+            _ => 3,
+        },
+        2 => match kinds[1] {
+            2 => 2,
+            _ => 1,
+        }
+        _ => 0,
+    }
+}
+
+fn get_part_2_kind(hand: &[usize]) -> usize {
+    let mut binding = hand
+        .iter()
+        .counts();
+
+    let jokers_count = binding.remove(&0).unwrap_or(0);
+
+    let mut kinds: Vec<_> = binding
+        .values()
+        .copied()
+        .collect();
+
+    kinds.sort();
+    kinds.reverse();
+
+    let first_kind = kinds.first().unwrap_or(&0_usize) + jokers_count;
+
+    if first_kind == 5 {
+        6
+    } else if first_kind == 4  {
+        5
+    } else if first_kind == 3 {
+        match kinds[1] {
+            2 => 4,
+            _ => 3,
+        }
+    } else if first_kind == 2 {
+        match kinds[1] {
+            2 => 2,
+            _ => 1,
+        }
+    } else {
+        0
+    }
+}
+
+/// Part 2 hand, part 1 hand and the bid, which fits a u32 so bids times ranks add up in a u128.
+type Hand = (Vec<usize>, Vec<usize>, u32);
+
+fn parse_hand(line: Line) -> Result<Hand, ParseError> {
+    let (cards_str, bid_str) = line.split_once(line.text, " ")?;
+    let cards = cards_str.chars().count();
+    if cards != 5 {
+        return Err(line.error(cards_str, format!("a hand must have 5 cards, not {}", cards)));
+    }
+    let mut hand: Vec<usize> = vec![];
+    let mut part_2_hand: Vec<usize> = vec![];
+    for (index, card) in cards_str.char_indices() {
+        let unknown_card = || line.error(&cards_str[index..index + card.len_utf8()], format!("unknown card {:?}", card));
+        hand.push(CARDS.find(card).ok_or_else(unknown_card)?);
+        part_2_hand.push(PART_2_CARDS.find(card).ok_or_else(unknown_card)?)
+    }
+    Ok((part_2_hand, hand, line.number(bid_str)?))
+}
+
+/// How many times each (kind, hand, bid) was dealt, in the order the parts rank them.
+type Ranking = BTreeMap<(usize, Vec<usize>, u32), usize>;
+
+/// Sums bids times ranks, equal entries take consecutive ranks like they do after sorting.
+fn total_winnings(ranking: &Ranking) -> u128 {
+    let mut ranked: u128 = 0;
+    let mut total = 0;
+    for (&(_, _, bid), &count) in ranking {
+        let count = count as u128;
+        // ranks ranked + 1 to ranked + count
+        total += u128::from(bid) * (count * ranked + count * (count + 1) / 2);
+        ranked += count;
+    }
+    total
+}
+
+/// Sums bids times ranks of hands sorted by rank already.
+fn winnings<'a>(bids: impl Iterator<Item=&'a u32>) -> u128 {
+    bids.enumerate().map(|(index, &bid)| (index as u128 + 1) * u128::from(bid)).sum()
+}
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+
+    type Input = Vec<Hand>;
+    type Answer1 = u128;
+    type Answer2 = u128;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut hands: Vec<Hand> = vec![];
+
+        for line in lines(input) {
+            hands.push(parse_hand(line)?);
+        }
+        Ok(hands)
+    }
+
+    fn part_1(hands: &Self::Input) -> u128 {
+        let mut hands_ranked: Vec<(usize, &Vec<usize>, &u32)> = hands.iter().map(
+            |(_, hand, bid)|
+                (get_kind(hand), hand, bid)
+        ).collect();
+        hands_ranked.sort();
+        winnings(hands_ranked.iter().map(|(_kind, _hand, bid)| *bid))
+    }
+
+    fn part_2(hands: &Self::Input) -> u128 {
+        let mut hands_ranked: Vec<(usize, &Vec<usize>, &u32)> = hands.iter().map(
+            |(part_2_hand, _, bid)|
+                (get_part_2_kind(part_2_hand), part_2_hand, bid)
+        ).collect();
+        hands_ranked.sort();
+        winnings(hands_ranked.iter().map(|(_kind, _part_2_hand, bid)| *bid))
+    }
+}
+
+/// Rankings of both parts, the exception to the constant memory of [`Streaming::State`]: they
+/// grow with the distinct hands and bids, at worst with the lines. The rank of a hand is only
+/// known once every hand is, and equal hands are ordered by their bids.
+#[derive(Default)]
+pub struct Rankings {
+    part_1: Ranking,
+    part_2: Ranking,
+}
+
+impl Streaming for Day7 {
+    type State = Rankings;
+
+    fn feed(rankings: &mut Rankings, line: Line) -> Result<(), ParseError> {
+        let (part_2_hand, hand, bid) = parse_hand(line)?;
+        *rankings.part_1.entry((get_kind(&hand), hand, bid)).or_default() += 1;
+        *rankings.part_2.entry((get_part_2_kind(&part_2_hand), part_2_hand, bid)).or_default() += 1;
+        Ok(())
+    }
+
+    fn finish(rankings: Rankings) -> (u128, u128) {
+        (total_winnings(&rankings.part_1), total_winnings(&rankings.part_2))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_shared::answers::InputHasher;
+    use crate::registry::Part;
+    use crate::settings::Settings;
+    use crate::stream::{stream, StreamError};
+    use crate::Solution;
+    use super::Day7;
+
+    const INPUT: &str = include_str!("../examples/day07/example.txt");
+
+    #[test]
+    fn parse_test() {
+        assert_eq!(
+            Day7::parse(INPUT).unwrap(),
+            vec![
+                (vec![2, 1, 9, 2, 11], vec![1, 0, 8, 1, 11], 765),
+                (vec![9, 4, 4, 0, 4], vec![8, 3, 3, 9, 3], 684),
+                (vec![11, 11, 5, 6, 6], vec![11, 11, 4, 5, 5], 28),
+                (vec![11, 9, 0, 0, 9], vec![11, 8, 9, 9, 8], 220),
+                (vec![10, 10, 10, 0, 12], vec![10, 10, 10, 9, 12], 483),
+            ]);
+    }
+
+    #[test]
+    fn stream_repeated_hands_test() {
+        let input = "32T3K 765\nKK677 28\n32T3K 765\n32T3K 1\nKK677 28\n";
+        let parsed = Day7::parse(input).unwrap();
+        let streamed = stream::<Day7>(&mut input.as_bytes(), &mut InputHasher::default(), &Settings::default(), &[Part::One, Part::Two]).unwrap();
+        assert_eq!(streamed, (Day7::part_1(&parsed), Day7::part_2(&parsed)));
+    }
+
+    #[test]
+    fn large_bids_test() {
+        // a panic before, bids times ranks overflowed
+        let input = "32T3K 4294967295\nKK677 4294967295\n";
+        let parsed = Day7::parse(input).unwrap();
+        assert_eq!(Day7::part_1(&parsed), 3 * 4294967295);
+        let streamed = stream::<Day7>(&mut input.as_bytes(), &mut InputHasher::default(), &Settings::default(), &[Part::One, Part::Two]).unwrap();
+        assert_eq!(streamed, (3 * 4294967295, 3 * 4294967295));
+        let error = Day7::parse("32T3K 18446744073709551615").unwrap_err();
+        assert_eq!(error.snippet, "18446744073709551615");
+    }
+
+    #[test]
+    fn parse_error_test() {
+        let error = Day7::parse("32T3K 765\nT55X5 684").unwrap_err();
+        assert_eq!((error.line, error.column, error.snippet.as_str()), (2, 4, "X"));
+        let error = Day7::parse("32T3K").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 1));
+        let error = Day7::parse("32T3K 765\nAAA 5").unwrap_err();
+        assert_eq!((error.line, error.column, error.snippet.as_str()), (2, 1, "AAA"));
+        let error = Day7::parse(" 5").unwrap_err();
+        assert_eq!((error.line, error.message.as_str()), (1, "a hand must have 5 cards, not 0"));
+        let error = stream::<Day7>(&mut "AAA 5".as_bytes(), &mut InputHasher::default(), &Settings::default(), &[Part::One, Part::Two]).unwrap_err();
+        assert!(matches!(error, StreamError::Parse(error) if error.line == 1));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,335 @@
+/*
+Part 2 of the day 8 is quite annoying. Definitely it is not programming puzzle.
+
+It is solvable with LCM when input fits, and it fits. Not coincidentally :/ It can count cycle length for each starting point.
+
+So this program is just brute force solution that can find a solution in a few hours.
+*/
+
+use std::collections::HashMap;
+
+use crate::debug;
+use crate::log::Progress;
+use crate::parse::{lines, Line, ParseError};
+use crate::registry::Part;
+use crate::Solution;
+
+pub struct Day8;
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+enum Direction {
+    Left = 0,
+    Right = 1,
+}
+
+type Navigation = Vec<Direction>;
+
+type NodeId = [char; 3];
+
+type Nodes = Vec<[usize; 2]>;
+type NodeMap = HashMap<NodeId, usize>;
+
+const START: NodeId = ['A', 'A', 'A'];
+const END: NodeId = ['Z', 'Z', 'Z'];
+
+pub struct Network {
+    navigation: Navigation,
+    nodes: Nodes,
+    node_map: NodeMap,
+    /// Every node ending with A, with an error at its line for when it gets nowhere.
+    starts: Vec<(usize, ParseError)>,
+    /// The number of lines, to point past them when a node is missing.
+    lines: usize,
+}
+
+fn parse_node_id(line: &Line, id: &str) -> Result<NodeId, ParseError> {
+    id.chars().collect::<Vec<char>>().try_into().map_err(|_| line.error(id, "node id must have three characters"))
+}
+
+fn parse(input: &str) -> Result<Network, ParseError> {
+    let mut lines = lines(input);
+    let first_line = lines.next().ok_or_else(|| ParseError::end_of_input(1, "missing navigation"))?;
+    let navigation: Navigation = first_line.text.char_indices().map(
+        |(index, char)|
+            match char {
+                'L' => Ok(Direction::Left),
+                'R' => Ok(Direction::Right),
+                _ => Err(first_line.error(&first_line.text[index..index + char.len_utf8()], "direction must be L or R")),
+            }
+    )
+        .collect::<Result<_, _>>()?;
+    if navigation.is_empty() {
+        return Err(first_line.error(first_line.text, "navigation must not be empty"));
+    }
+
+    lines.next(); // empty line
+
+    let mut pre_nodes: Vec<(Line, [&str; 2])> = Vec::new();
+    let mut node_map: NodeMap = HashMap::new();
+    let mut starts = vec![];
+    for line in lines {
+        let (source_str, target_str) = line.split_once(line.text, " = (")?;
+        let source = source_str.trim();
+        let target_str = target_str.strip_suffix(')').ok_or_else(|| line.error(target_str, "expected \")\""))?;
+        let (target_l, target_r) = line.split_once(target_str, ", ")?;
+        let node_id = parse_node_id(&line, source)?;
+        pre_nodes.push((line, [target_l, target_r]));
+        node_map.insert(node_id, pre_nodes.len() - 1);
+        if node_id[2] == 'A' {
+            starts.push((pre_nodes.len() - 1, line.error(source, "")));
+        }
+    }
+    let lines = pre_nodes.last().map_or(first_line.number, |(line, _)| line.number);
+
+    let mut nodes: Nodes = vec![];
+    for (line, targets) in pre_nodes.iter() {
+        let [left, right] = targets.map(
+            |target|
+                node_map.get(&parse_node_id(line, target)?).copied().ok_or_else(|| line.error(target, "unknown node"))
+        );
+        nodes.push([left?, right?]);
+    }
+
+    Ok(Network { navigation, nodes, node_map, starts, lines })
+}
+
+/// Steps from `start` to the first node `is_end` accepts, `None` when the walk goes round
+/// without one.
+fn steps(Network { navigation, nodes, .. }: &Network, start: usize, is_end: impl Fn(usize) -> bool) -> Option<usize> {
+    // a node at the same point of the navigation as before repeats the walk from there
+    let walk = navigation.iter().cycle().enumerate().take(nodes.len() * navigation.len());
+    let mut current_node = start;
+    for (counter, direction) in walk {
+        if is_end(current_node) {
+            return Some(counter);
+        }
+        current_node = nodes[current_node][*direction as usize];
+    }
+    None
+}
+
+fn ends_with_z(Network { nodes, node_map, .. }: &Network) -> Vec<bool> {
+    let mut ends = vec![false; nodes.len()];
+    for (key, &val) in node_map.iter() {
+        ends[val] = key[2] == 'Z';
+    }
+    ends
+}
+
+fn check(network: &Network, part: Part) -> Result<(), ParseError> {
+    match part {
+        Part::One => {
+            let missing = |id: &NodeId| ParseError::end_of_input(network.lines + 1, format!("part 1 needs the node {}", id.iter().collect::<String>()));
+            let start = *network.node_map.get(&START).ok_or_else(|| missing(&START))?;
+            let end = *network.node_map.get(&END).ok_or_else(|| missing(&END))?;
+            if steps(network, start, |node| node == end).is_none() {
+                let (_, error) = network.starts.iter().find(|(node, _)| *node == start).unwrap();
+                return Err(ParseError { message: "ZZZ cannot be reached from here".to_string(), ..error.clone() });
+            }
+        }
+        Part::Two => {
+            if network.starts.is_empty() {
+                return Err(ParseError::end_of_input(network.lines + 1, "part 2 needs a node ending with A"));
+            }
+            if lcm(&cycle_lengths(network)?).is_none() {
+                return Err(ParseError::end_of_input(network.lines + 1, "the ghosts meet after more than 2^128 steps"));
+            }
+        }
+    }
+    Ok(())
+}
+
+fn part_1(network: &Network) -> usize {
+    // Day8::check makes sure both nodes are there and the walk gets from one to the other
+    let ending_node = network.node_map[&END];
+    steps(network, network.node_map[&START], |node| node == ending_node).unwrap()
+}
+
+
+/// How long each ghost walks to its first ending node, or an error at the start of one that
+/// never gets to any.
+fn cycle_lengths(network: &Network) -> Result<Vec<usize>, ParseError> {
+    let ends = ends_with_z(network);
+    network.starts
+        .iter()
+        .map(|(start, error)| {
+            let cycle_length = steps(network, *start, |node| ends[node])
+                .ok_or_else(|| ParseError { message: "no node ending with Z can be reached from here".to_string(), ..error.clone() })?;
+            debug!("find cycle for {}: {}", start, cycle_length);
+            Ok(cycle_length)
+        })
+        .collect()
+}
+
+fn gcd(a: u128, b: u128) -> u128 {
+    if b == 0 { a } else { gcd(b, a % b) }
+}
+
+/// `None` when the least common multiple does not fit.
+fn lcm(lengths: &[usize]) -> Option<u128> {
+    lengths.iter().try_fold(1u128, |lcm, &length| (lcm / gcd(lcm, length as u128)).checked_mul(length as u128))
+}
+
+pub fn part_2_lcm(network: &Network) -> u128 {
+    /* assumimg exactly one cycle per starting node, whose length is the way to its first ending node */
+
+    // Day8::check makes sure every start gets to an end and the multiple fits
+    lcm(&cycle_lengths(network).unwrap()).unwrap()
+}
+
+pub fn part_2_brute_force(Network { navigation, nodes, node_map, .. }: &Network) -> usize {
+    let mut current_nodes: Vec<usize> = node_map.iter().filter(|(key, _val)| key[2] == 'A').map(|(_key, val)| *val).collect();
+    let ending_nodes: Vec<usize> = node_map.iter().filter(|(key, _val)| key[2] == 'Z').map(|(_key, val)| *val).collect();
+
+    let mut progress = Progress::new("day 8 brute force", None);
+    for (counter, direction) in navigation.iter().cycle().enumerate() {
+        if current_nodes.iter().all(|idx| ending_nodes.contains(idx)) {
+            return counter;
+        }
+
+        current_nodes = current_nodes.iter().map(|idx| nodes[*idx][*direction as usize]).collect();
+        progress.tick(counter as u64);
+    }
+    unreachable!()
+}
+
+
+pub fn part_2_unrolled(Network { navigation, nodes, node_map, .. }: &Network) -> usize {
+    // only for six starting and ending nodes
+
+    let mut current_nodes: [usize; 6] = node_map.iter().filter(|(key, _val)| key[2] == 'A').map(|(_key, val)| *val).collect::<Vec<_>>().try_into().unwrap();
+    let ending_nodes: [usize; 6] = node_map.iter().filter(|(key, _val)| key[2] == 'Z').map(|(_key, val)| *val).collect::<Vec<_>>().try_into().unwrap();
+
+    let mut progress = Progress::new("day 8 unrolled", None);
+    for (counter, direction) in navigation.iter().cycle().enumerate() {
+        if ending_nodes.contains(&current_nodes[0])
+            && ending_nodes.contains(&current_nodes[1])
+            && ending_nodes.contains(&current_nodes[2])
+            && ending_nodes.contains(&current_nodes[3])
+            && ending_nodes.contains(&current_nodes[4])
+            && ending_nodes.contains(&current_nodes[5]) {
+            return counter;
+        }
+
+        current_nodes[0] = nodes[current_nodes[0]][*direction as usize];
+        current_nodes[1] = nodes[current_nodes[1]][*direction as usize];
+        current_nodes[2] = nodes[current_nodes[2]][*direction as usize];
+        current_nodes[3] = nodes[current_nodes[3]][*direction as usize];
+        current_nodes[4] = nodes[current_nodes[4]][*direction as usize];
+        current_nodes[5] = nodes[current_nodes[5]][*direction as usize];
+        progress.tick(counter as u64);
+    }
+    unreachable!()
+}
+
+
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+
+    type Input = Network;
+    type Answer1 = usize;
+    type Answer2 = u128;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(network: &Self::Input) -> usize {
+        part_1(network)
+    }
+
+    fn part_2(network: &Self::Input) -> u128 {
+        part_2_lcm(network)
+    }
+
+    fn check(network: &Self::Input, part: Part) -> Result<(), ParseError> {
+        check(network, part)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use crate::registry::Part;
+    use super::{check, parse, part_2_brute_force, part_2_lcm, part_2_unrolled, Direction};
+
+    const INPUT: &str = include_str!("../examples/day08/example.txt");
+
+    #[test]
+    fn parse_test() {
+        let network = parse(INPUT).unwrap();
+        assert_eq!(network.navigation, vec![Direction::Right, Direction::Left]);
+        assert_eq!(network.nodes[network.node_map[&['A', 'A', 'A']]], [1, 2]);
+    }
+
+    #[test]
+    fn parse_error_test() {
+        let error = parse("RLX\n\nAAA = (AAA, AAA)").err().unwrap();
+        assert_eq!((error.line, error.column, error.snippet.as_str()), (1, 3, "X"));
+        let error = parse("RL\n\nAAA = (AAA, AAAA)").err().unwrap();
+        assert_eq!((error.line, error.column, error.snippet.as_str()), (3, 13, "AAAA"));
+        let error = parse("RL\n\nAAA = (AAA, BBB)").err().unwrap();
+        assert_eq!((error.line, error.column, error.message.as_str()), (3, 13, "unknown node"));
+        let error = parse("\n\nAAA = (AAA, AAA)").err().unwrap();
+        assert_eq!((error.line, error.message.as_str()), (1, "navigation must not be empty"));
+    }
+
+    #[test]
+    fn check_test() {
+        let network = parse("L\n\nAAA = (AAA, ZZZ)\nBBA = (ZZZ, ZZZ)\nZZZ = (ZZZ, ZZZ)").unwrap();
+        let error = check(&network, Part::One).unwrap_err();
+        assert_eq!((error.line, error.column, error.snippet.as_str()), (3, 1, "AAA"));
+        assert_eq!(error.message, "ZZZ cannot be reached from here");
+        let error = check(&network, Part::Two).unwrap_err();
+        assert_eq!((error.line, error.message.as_str()), (3, "no node ending with Z can be reached from here"));
+
+        let network = parse(include_str!("../examples/day08/example3.txt")).unwrap();
+        let error = check(&network, Part::One).unwrap_err();
+        assert_eq!((error.line, error.message.as_str()), (11, "part 1 needs the node AAA"));
+        assert!(check(&network, Part::Two).is_ok());
+
+        let network = parse("L\n\nZZZ = (ZZZ, ZZZ)").unwrap();
+        let error = check(&network, Part::Two).unwrap_err();
+        assert_eq!((error.line, error.message.as_str()), (4, "part 2 needs a node ending with A"));
+    }
+
+    const NAMES: &[u8] = b"0123456789BCDEFGHIJKLMNOPQRSTUVWXY";
+
+    /// Like the real inputs, every ghost walks from its start into a ring reaching its end node
+    /// once per lap, and the laps are multiples of the navigation length.
+    fn network(ghosts: std::ops::Range<usize>) -> impl Strategy<Value=String> {
+        (prop::collection::vec(prop::bool::ANY, 1..5), prop::collection::vec(1..6usize, ghosts)).prop_map(
+            |(navigation, laps)| {
+                let mut input: String = navigation.iter().map(|&left| if left { 'L' } else { 'R' }).collect();
+                input.push('\n');
+                for (ghost, laps) in laps.iter().enumerate() {
+                    let ghost = NAMES[ghost] as char;
+                    let (start, end) = (format!("{}0A", ghost), format!("{}0Z", ghost));
+                    let length = laps * navigation.len();
+                    // the node reached after `index` steps of a lap
+                    let step = |index: usize| if index == length { end.clone() } else { format!("{}{}R", ghost, NAMES[index] as char) };
+                    for (index, node) in std::iter::once(start.clone()).chain((1..length).map(step)).enumerate() {
+                        input += &format!("\n{} = ({}, {})", node, step(index + 1), step(index + 1));
+                    }
+                    input += &format!("\n{} = ({}, {})", end, step(1), step(1));
+                }
+                input
+            }
+        )
+    }
+
+    proptest! {
+        #[test]
+        fn lcm_agrees_with_brute_force(input in network(1..5)) {
+            let network = parse(&input).unwrap();
+            prop_assert_eq!(part_2_lcm(&network), part_2_brute_force(&network) as u128);
+        }
+
+        #[test]
+        fn unrolled_agrees_with_brute_force(input in network(6..7)) {
+            let network = parse(&input).unwrap();
+            prop_assert_eq!(part_2_unrolled(&network), part_2_brute_force(&network));
+        }
+    }
+}
@@ -0,0 +1,146 @@
+use crate::parse::{lines, Line, ParseError};
+use crate::stream::Streaming;
+use crate::Solution;
+
+pub struct Day9;
+
+/// Bits of an extrapolated value at most, see [`parse_history`], inputs of less than 2^27 lines
+/// then add up within `i128`.
+const MAX_BITS: u32 = 100;
+
+fn get_polynom_coeficients(count: usize) -> Vec<i128> {
+    let mut previous = Vec::new();
+    for _ in 0..count {
+        let mut new: Vec<_> = previous.iter().map(|x| -x).collect();
+        new.push(1);
+        for idx in 1..new.len() {
+            new[idx] += previous[idx - 1]
+        }
+        previous = new;
+    }
+    previous
+}
+
+/// The next and the previous value of a history.
+fn extrapolate(history: &[isize]) -> (i128, i128) {
+    let coeficients = get_polynom_coeficients(history.len());
+    (
+        coeficients.iter().zip(history).map(|(coef, &num)| coef * num as i128).sum(),
+        coeficients.iter().zip(history.iter().rev()).map(|(coef, &num)| coef * num as i128).sum(),
+    )
+}
+
+/// The coefficients add up to at most 2^n in absolute value for n values, so the bits of the
+/// largest value and the length of the history bound the bits of the extrapolation.
+fn parse_history(line: Line) -> Result<Vec<isize>, ParseError> {
+    let history: Vec<isize> = line.numbers(line.text)?;
+    let largest = history.iter().map(|value| value.unsigned_abs()).max().unwrap_or(0);
+    if (usize::BITS - largest.leading_zeros()) as usize + history.len() > MAX_BITS as usize {
+        return Err(line.error(line.text, format!("the history could extrapolate past {} bits", MAX_BITS)));
+    }
+    Ok(history)
+}
+
+impl Solution for Day9 {
+    const DAY: u8 = 9;
+
+    type Input = Vec<Vec<isize>>;
+    type Answer1 = i128;
+    type Answer2 = i128;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        lines(input)
+            .map(parse_history)
+            .collect()
+    }
+
+    fn part_1(lines: &Self::Input) -> i128 {
+        lines.iter().map(|line| extrapolate(line).0).sum::<i128>()
+    }
+
+    fn part_2(lines: &Self::Input) -> i128 {
+        lines.iter().map(|line| extrapolate(line).1).sum::<i128>()
+    }
+}
+
+impl Streaming for Day9 {
+    type State = (i128, i128);
+
+    fn feed(answers: &mut Self::State, line: Line) -> Result<(), ParseError> {
+        let (next, previous) = extrapolate(&parse_history(line)?);
+        answers.0 += next;
+        answers.1 += previous;
+        Ok(())
+    }
+
+    fn finish(answers: Self::State) -> (i128, i128) {
+        answers
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use crate::Solution;
+    use super::Day9;
+
+    const INPUT: &str = include_str!("../examples/day09/example.txt");
+
+    #[test]
+    fn parse_test() {
+        assert_eq!(Day9::parse(INPUT).unwrap()[1], vec![1, 3, 6, 10, 15, 21]);
+    }
+
+    #[test]
+    fn parse_error_test() {
+        let error = Day9::parse("0 3 6\n1 3 - 10").unwrap_err();
+        assert_eq!((error.line, error.column, error.snippet.as_str()), (2, 5, "-"));
+        let error = Day9::parse("0 3 6\n9223372036854775807 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0").unwrap_err();
+        assert_eq!((error.line, error.message.as_str()), (2, "the history could extrapolate past 100 bits"));
+    }
+
+    #[test]
+    fn largest_history_test() {
+        // 63 bits and 37 values, the coefficients reach C(37, 18) in the middle
+        let history: Vec<String> = (0..37).map(|x| if x % 2 == 0 { isize::MAX } else { isize::MIN + 1 }.to_string()).collect();
+        let parsed = Day9::parse(&history.join(" ")).unwrap();
+        let (next, previous) = difference_table(&parsed[0]);
+        assert_eq!((Day9::part_1(&parsed), Day9::part_2(&parsed)), (next, previous));
+    }
+
+    /// Extrapolates both ends by building the table of differences down to zeros.
+    fn difference_table(history: &[isize]) -> (i128, i128) {
+        let mut rows: Vec<Vec<i128>> = vec![history.iter().map(|&x| x as i128).collect()];
+        while rows.last().unwrap().iter().any(|&x| x != 0) {
+            let row = rows.last().unwrap().windows(2).map(|pair| pair[1] - pair[0]).collect();
+            rows.push(row);
+        }
+        rows.iter().rev().fold((0, 0), |(next, previous), row| match (row.first(), row.last()) {
+            (Some(first), Some(last)) => (last + next, first - previous),
+            _ => (next, previous),
+        })
+    }
+
+    fn histories() -> impl Strategy<Value=Vec<Vec<isize>>> {
+        prop::collection::vec(prop::collection::vec(-1000..1000isize, 1..12), 1..5)
+    }
+
+    proptest! {
+        #[test]
+        fn coefficients_agree_with_difference_table(histories in histories()) {
+            let input = histories
+                .iter()
+                .map(|history| history.iter().map(|x| x.to_string()).collect::<Vec<_>>().join(" "))
+                .collect::<Vec<_>>()
+                .join("\n");
+            let parsed = Day9::parse(&input).unwrap();
+            let (next, previous) = histories
+                .iter()
+                .map(|history| difference_table(history))
+                .fold((0, 0), |(next, previous), (n, p)| (next + n, previous + p));
+            prop_assert_eq!(Day9::part_1(&parsed), next);
+            prop_assert_eq!(Day9::part_2(&parsed), previous);
+        }
+    }
+}
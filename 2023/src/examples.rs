@@ -0,0 +1,35 @@
+//! Tests generated by build.rs from the example fixtures in `examples/dayNN/` and the fuzzing
+//! crashes in `fuzz/regressions/dayNN/`.
+
+use aoc_shared::answers::InputHasher;
+use aoc_shared::registry::{Parsed, Part};
+use aoc_shared::settings::Settings;
+use crate::YEAR;
+
+fn parse(day: u8, input: &str) -> Box<dyn Parsed> {
+    let day = YEAR.find(day).unwrap_or_else(|| panic!("day {} is not registered", day));
+    day.parse(input).unwrap_or_else(|err| panic!("{}", err))
+}
+
+fn parses(day: u8, input: &str) {
+    parse(day, input);
+}
+
+/// The parser may reject the input and the parts may have no answer for it, but neither may panic.
+fn survives(day: u8, input: &[u8]) {
+    let day = YEAR.find(day).unwrap_or_else(|| panic!("day {} is not registered", day));
+    if let Some(Ok(parsed)) = std::str::from_utf8(input).ok().map(|input| day.parse(input)) {
+        let _ = parsed.part(Part::One);
+        let _ = parsed.part(Part::Two);
+    }
+}
+
+/// Streaming days have to agree with themselves parsing the whole input.
+fn check(day: u8, input: &str, part: Part, expected: &str) {
+    assert_eq!(parse(day, input).part(part).unwrap_or_else(|err| panic!("{}", err)), expected);
+    if let Some(streamed) = YEAR.find(day).unwrap().stream(&mut input.as_bytes(), &mut InputHasher::default(), &Settings::default(), &[Part::One, Part::Two]) {
+        assert_eq!(streamed.unwrap_or_else(|err| panic!("{}", err))[part as usize - 1], expected, "streamed");
+    }
+}
+
+include!(concat!(env!("OUT_DIR"), "/examples.rs"));
@@ -0,0 +1,320 @@
+//! Synthetic inputs in the format of every day, deterministic for a seed so they can serve
+//! as benchmark and test fixtures.
+
+use std::fmt::Write;
+
+use aoc_shared::generate::{Knobs, Rng};
+
+const DIGIT_WORDS: [&str; 9] = ["one", "two", "three", "four", "five", "six", "seven", "eight", "nine"];
+
+fn day01(rng: &mut Rng, knobs: &Knobs) -> String {
+    let (size, width) = (knobs.size.unwrap_or(1000), knobs.width.unwrap_or(40).max(1));
+    let mut input = String::new();
+    for _ in 0..size {
+        let length = rng.between(1, width as u64) as usize;
+        let mut line = String::new();
+        while line.len() < length {
+            match rng.between(0, 9) {
+                0..=5 => line.push(rng.between(b'a' as u64, b'z' as u64) as u8 as char),
+                6 | 7 => line.push(char::from_digit(rng.between(1, 9) as u32, 10).unwrap()),
+                _ => line.push_str(rng.pick(&DIGIT_WORDS)),
+            }
+        }
+        // part 1 needs a digit on every line
+        let at = rng.between(0, line.len() as u64) as usize;
+        line.insert(at, char::from_digit(rng.between(1, 9) as u32, 10).unwrap());
+        writeln!(input, "{}", line).unwrap();
+    }
+    input
+}
+
+fn day02(rng: &mut Rng, knobs: &Knobs) -> String {
+    let size = knobs.size.unwrap_or(100);
+    let mut input = String::new();
+    for game in 1..=size {
+        let sets: Vec<String> = (0..rng.between(1, 6))
+            .map(|_| {
+                let mut colors = ["red", "green", "blue"];
+                rng.shuffle(&mut colors);
+                let count = rng.between(1, 3) as usize;
+                colors[..count].iter().map(|color| format!("{} {}", rng.between(1, 20), color)).collect::<Vec<_>>().join(", ")
+            })
+            .collect();
+        writeln!(input, "Game {}: {}", game, sets.join("; ")).unwrap();
+    }
+    input
+}
+
+fn day03(rng: &mut Rng, knobs: &Knobs) -> String {
+    let (size, width) = (knobs.size.unwrap_or(140), knobs.width.unwrap_or(140));
+    let mut input = String::new();
+    for _ in 0..size {
+        let mut row = String::new();
+        while row.len() < width {
+            let digits = rng.between(1, 3) as usize;
+            match rng.between(0, 99) {
+                0..=11 if row.len() + digits <= width => {
+                    write!(row, "{}", rng.between(10u64.pow(digits as u32 - 1), 10u64.pow(digits as u32) - 1)).unwrap();
+                    if row.len() < width {
+                        row.push('.');
+                    }
+                }
+                12..=19 => row.push(*rng.pick(&['*', '#', '+', '$', '/', '@', '=', '%', '-', '&'])),
+                _ => row.push('.'),
+            }
+        }
+        writeln!(input, "{}", row).unwrap();
+    }
+    input
+}
+
+fn day04(rng: &mut Rng, knobs: &Knobs) -> String {
+    let (size, width) = (knobs.size.unwrap_or(200), knobs.width.unwrap_or(10).clamp(1, 28));
+    let have = width * 5 / 2;
+    let mut input = String::new();
+    for card in 1..=size {
+        let mut numbers: Vec<u64> = (1..=99).collect();
+        rng.shuffle(&mut numbers);
+        // few wins keep the copies of part 2 from growing exponentially
+        let wins = if rng.chance(20) { rng.between(1, width.min(7) as u64) as usize } else { 0 };
+        let winning = &numbers[..width];
+        let mut own: Vec<u64> = winning[..wins].iter().chain(&numbers[width..width + have - wins]).copied().collect();
+        rng.shuffle(&mut own);
+        let format = |numbers: &[u64]| numbers.iter().map(|number| format!("{:>2}", number)).collect::<Vec<_>>().join(" ");
+        writeln!(input, "Card {:>4}: {} | {}", card, format(winning), format(&own)).unwrap();
+    }
+    input
+}
+
+const MAP_NAMES: [&str; 8] = ["seed", "soil", "fertilizer", "water", "light", "temperature", "humidity", "location"];
+
+fn day05(rng: &mut Rng, knobs: &Knobs) -> String {
+    let (size, maps) = (knobs.size.unwrap_or(40).max(1), knobs.maps.unwrap_or(7));
+    const SPACE: u64 = 1 << 32;
+    let seeds: Vec<String> = (0..10)
+        .map(|_| {
+            let length = rng.between(1, 1 << 28);
+            format!("{} {}", rng.between(0, SPACE - length), length)
+        })
+        .collect();
+    let mut input = format!("seeds: {}\n", seeds.join(" "));
+    for map in 0..maps {
+        let name = |index: usize| MAP_NAMES.get(index).map_or_else(|| format!("x{}", index), |name| name.to_string());
+        write!(input, "\n{}-to-{} map:\n", name(map), name(map + 1)).unwrap();
+        // a permutation of the whole space, cut into `size` segments
+        let mut cuts: Vec<u64> = (1..size).map(|_| rng.between(1, SPACE - 1)).collect();
+        cuts.extend([0, SPACE]);
+        cuts.sort();
+        cuts.dedup();
+        let mut destinations: Vec<(u64, u64)> = cuts.windows(2).map(|pair| (pair[0], pair[1] - pair[0])).collect();
+        rng.shuffle(&mut destinations);
+        let mut destination = 0;
+        let mut lines: Vec<String> = destinations
+            .iter()
+            .map(|&(source, length)| {
+                let line = format!("{} {} {}", destination, source, length);
+                destination += length;
+                line
+            })
+            .collect();
+        rng.shuffle(&mut lines);
+        input += &lines.join("\n");
+        input.push('\n');
+    }
+    input
+}
+
+/// Part 2 joins the races, the distances of more would not fit its numbers.
+const MAX_RACES: usize = 4;
+
+fn day06(rng: &mut Rng, knobs: &Knobs) -> String {
+    let size = knobs.size.unwrap_or(4).clamp(1, MAX_RACES);
+    let races: Vec<(u64, u64)> = (0..size)
+        .map(|_| {
+            let time = rng.between(7, 99);
+            let best = (time / 2) * (time - time / 2);
+            (time, rng.between(time / 2, best - 1))
+        })
+        .collect();
+    let times: Vec<String> = races.iter().map(|(time, _)| format!("{:>5}", time)).collect();
+    let distances: Vec<String> = races.iter().map(|(_, distance)| format!("{:>5}", distance)).collect();
+    format!("Time:    {}\nDistance:{}\n", times.join(""), distances.join(""))
+}
+
+fn day07(rng: &mut Rng, knobs: &Knobs) -> String {
+    let size = knobs.size.unwrap_or(1000);
+    let cards: Vec<char> = "23456789TJQKA".chars().collect();
+    let mut input = String::new();
+    for _ in 0..size {
+        let hand: String = (0..5).map(|_| *rng.pick(&cards)).collect();
+        writeln!(input, "{} {}", hand, rng.between(1, 1000)).unwrap();
+    }
+    input
+}
+
+const NAME_CHARS: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789";
+/// Ring nodes end with neither A nor Z.
+const RING_LAST_CHARS: &[u8] = b"BCDEFGHIJKLMNOPQRSTUVWXY0123456789";
+const PRIMES: [u64; 16] = [5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61];
+
+/// Like the real inputs, every ghost walks from its start into a ring reaching its end node once
+/// per lap, and a lap takes the navigation a prime number of times. Both directions lead on,
+/// so the answer of part 2 is the navigation length times the primes.
+fn day08(rng: &mut Rng, knobs: &Knobs) -> Result<String, String> {
+    let (width, ghosts) = (knobs.width.unwrap_or(12).max(1), knobs.ghosts.unwrap_or(6).clamp(1, PRIMES.len()));
+    let mut input: String = (0..width).map(|_| if rng.chance(50) { 'L' } else { 'R' }).collect();
+    input.push('\n');
+
+    let mut primes = PRIMES;
+    rng.shuffle(&mut primes);
+    let prefixes = NAME_CHARS.len() * NAME_CHARS.len();
+    let name = |index: usize, last: u8| {
+        let index = index % prefixes;
+        String::from_utf8(vec![NAME_CHARS[index / NAME_CHARS.len()], NAME_CHARS[index % NAME_CHARS.len()], last]).unwrap()
+    };
+    let ring_names = prefixes * RING_LAST_CHARS.len();
+    let mut ring_index = 0;
+    let mut lines = vec![];
+    for (ghost, prime) in primes[..ghosts].iter().enumerate() {
+        let (start, end) = match ghost {
+            0 => ("AAA".to_string(), "ZZZ".to_string()),
+            _ => (name(ghost, b'A'), name(ghost, b'Z')),
+        };
+        let length = width * *prime as usize;
+        if ring_index + length > ring_names {
+            return Err(format!("more than {} nodes, make the navigation shorter or use fewer ghosts", ring_names));
+        }
+        let ring: Vec<String> = (ring_index..ring_index + length - 1)
+            .map(|index| name(index, RING_LAST_CHARS[index / prefixes]))
+            .collect();
+        ring_index += length - 1;
+        // the node reached after `index` steps of a lap
+        let step = |index: usize| if index == length { end.clone() } else { ring[index - 1].clone() };
+        for (index, node) in std::iter::once(start.clone()).chain(ring.iter().cloned()).enumerate() {
+            lines.push(format!("{} = ({}, {})", node, step(index + 1), step(index + 1)));
+        }
+        lines.push(format!("{} = ({}, {})", end, step(1), step(1)));
+    }
+    rng.shuffle(&mut lines);
+    Ok(format!("{}\n{}\n", input, lines.join("\n")))
+}
+
+/// Values of a history, day 9 rejects longer ones of values this large.
+const MAX_HISTORY: usize = 60;
+
+fn day09(rng: &mut Rng, knobs: &Knobs) -> String {
+    let (size, width) = (knobs.size.unwrap_or(200), knobs.width.unwrap_or(21).clamp(1, MAX_HISTORY));
+    let mut input = String::new();
+    for _ in 0..size {
+        let degree = rng.between(0, width.min(6) as u64 - 1);
+        let coefficients: Vec<i64> = (0..=degree).map(|_| rng.between(0, 10) as i64 - 5).collect();
+        let values: Vec<String> = (0..width as i64)
+            .map(|x| coefficients.iter().rev().fold(0, |value, coefficient| value * x + coefficient).to_string())
+            .collect();
+        writeln!(input, "{}", values.join(" ")).unwrap();
+    }
+    input
+}
+
+/// An input for `day`, the same for the same knobs.
+pub fn generate(day: u8, knobs: &Knobs) -> Result<String, String> {
+    let rng = &mut Rng::new(knobs.seed);
+    match day {
+        1 => Ok(day01(rng, knobs)),
+        2 => Ok(day02(rng, knobs)),
+        3 => Ok(day03(rng, knobs)),
+        4 => Ok(day04(rng, knobs)),
+        5 => Ok(day05(rng, knobs)),
+        6 => Ok(day06(rng, knobs)),
+        7 => Ok(day07(rng, knobs)),
+        8 => day08(rng, knobs),
+        9 => Ok(day09(rng, knobs)),
+        _ => Err(format!("no generator for day {}", day)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env::temp_dir;
+    use std::fs;
+
+    use aoc_shared::answers::{input_hash, InputHasher};
+    use aoc_shared::generate::Knobs;
+    use aoc_shared::registry::Part;
+    use aoc_shared::settings::Settings;
+    use crate::{DAYS, YEAR};
+    use super::{generate, MAX_HISTORY, MAX_RACES, PRIMES};
+
+    fn small(seed: u64) -> Knobs {
+        Knobs { seed, size: Some(3), width: Some(8), ghosts: Some(3), maps: Some(3) }
+    }
+
+    #[test]
+    fn generated_inputs_are_solved_test() {
+        for day in DAYS {
+            let input = generate(day.day, &small(7)).unwrap();
+            let parsed = day.parse(&input).unwrap_or_else(|err| panic!("{}\n{}", err, input));
+            let answers = [parsed.part(Part::One).unwrap(), parsed.part(Part::Two).unwrap()];
+            if let Some(streamed) = day.stream(&mut input.as_bytes(), &mut InputHasher::default(), &Settings::default(), &[Part::One, Part::Two]) {
+                assert_eq!(streamed.unwrap(), answers, "day {}", day.day);
+            }
+        }
+    }
+
+    #[test]
+    fn largest_inputs_are_solved_test() {
+        // the largest knobs each generator takes, day 8 runs out of node names beyond them
+        let largest = |day: u8| match day {
+            4 => Knobs { width: Some(28), ..small(7) },
+            6 => Knobs { size: Some(MAX_RACES), ..small(7) },
+            8 => Knobs { width: Some(88), ghosts: Some(PRIMES.len()), ..small(7) },
+            9 => Knobs { width: Some(MAX_HISTORY), ..small(7) },
+            _ => Knobs { size: None, width: None, ..small(7) },
+        };
+        for day in DAYS {
+            let input = generate(day.day, &largest(day.day)).unwrap();
+            let parsed = day.parse(&input).unwrap_or_else(|err| panic!("day {}: {}", day.day, err));
+            for part in [Part::One, Part::Two] {
+                parsed.part(part).unwrap_or_else(|err| panic!("day {}: {}", day.day, err));
+            }
+        }
+        assert!(generate(8, &Knobs { width: Some(89), ghosts: Some(PRIMES.len()), ..small(7) }).is_err());
+    }
+
+    #[test]
+    fn mapped_inputs_agree_test() {
+        for day in DAYS {
+            let input = generate(day.day, &Knobs { size: Some(5000), ..small(7) }).unwrap();
+            let path = temp_dir().join(format!("aoc-generated-{}-{}", day.day, std::process::id()));
+            fs::write(&path, &input).unwrap();
+            let mut hasher = InputHasher::default();
+            let mapped = day.mapped(&path, &mut hasher, &Settings::default(), &[Part::One, Part::Two]);
+            fs::remove_file(&path).unwrap();
+            if let Some(mapped) = mapped {
+                let parsed = day.parse(&input).unwrap();
+                assert_eq!(mapped.unwrap(), [parsed.part(Part::One).unwrap(), parsed.part(Part::Two).unwrap()], "day {}", day.day);
+                assert_eq!(hasher.finish(), input_hash(&input));
+            }
+        }
+    }
+
+    #[test]
+    fn deterministic_test() {
+        for day in DAYS {
+            assert_eq!(generate(day.day, &small(7)), generate(day.day, &small(7)));
+            assert_ne!(generate(day.day, &small(7)), generate(day.day, &small(8)), "day {}", day.day);
+        }
+        assert!(generate(26, &Knobs::default()).is_err());
+    }
+
+    #[test]
+    fn day08_cycles_test() {
+        let knobs = Knobs { seed: 1, size: None, width: Some(4), ghosts: Some(2), maps: None };
+        let parsed = YEAR.find(8).unwrap().parse(&generate(8, &knobs).unwrap()).unwrap();
+        let lap: u64 = parsed.part(Part::One).unwrap().parse().unwrap();
+        let all: u64 = parsed.part(Part::Two).unwrap().parse().unwrap();
+        assert_eq!(lap % 4, 0);
+        assert_eq!(all % lap, 0);
+        assert!(all > lap);
+    }
+}
@@ -0,0 +1,105 @@
+// the days refer to the shared modules as if they were still part of this crate
+use aoc_shared::{debug, log, mapped, parse, registry, render, settings, stream, warn, Solution};
+
+pub mod generate;
+
+pub mod a1;
+pub mod a2;
+pub mod a3;
+pub mod a4;
+pub mod a5;
+pub mod a6;
+pub mod a7;
+pub mod a8;
+pub mod a9;
+
+#[cfg(test)]
+mod examples;
+
+use aoc_shared::{Day, Year};
+use settings::Settings;
+
+/// Every solved day, in order.
+pub const DAYS: &[Day] = &[
+    Day::chunked::<a1::Day1>(),
+    Day::streaming::<a2::Day2>(),
+    Day::new::<a3::Day3>(),
+    Day::streaming::<a4::Day4>(),
+    Day::new::<a5::Day5>(),
+    Day::new::<a6::Day6>(),
+    Day::streaming::<a7::Day7>(),
+    Day::new::<a8::Day8>(),
+    Day::streaming::<a9::Day9>(),
+];
+
+/// `vocabulary` takes a preset or a word list for day 1, see [`a1::Vocabulary::load`],
+/// `tokens` what its calibration values are made of, see [`a1::Tokens`], and `digitless` what
+/// becomes of its lines without any, see [`a1::Digitless`]. They end up in [`a1::Options`].
+fn configure(settings: &mut Settings, key: &str, value: &str) -> Result<bool, String> {
+    match key {
+        a1::VOCABULARY => {
+            let vocabulary = a1::Vocabulary::load(value)?;
+            let custom = vocabulary != a1::Vocabulary::default();
+            settings.set(a1::VOCABULARY, vocabulary);
+            Ok(custom)
+        }
+        a1::TOKENS => {
+            let tokens: a1::Tokens = value.parse()?;
+            settings.set(a1::TOKENS, tokens);
+            Ok(tokens != a1::Tokens::default())
+        }
+        a1::DIGITLESS => {
+            let digitless: a1::Digitless = value.parse()?;
+            settings.set(a1::DIGITLESS, digitless);
+            Ok(digitless != a1::Digitless::default())
+        }
+        _ => Err(format!("unknown setting {:?}, 2023 has vocabulary, tokens and digitless", key)),
+    }
+}
+
+pub const YEAR: Year = Year { year: 2023, days: DAYS, generate: generate::generate, configure };
+
+/// Turns colors on or off for a test comparing pictures until the guard is dropped, the
+/// setting is shared by all the tests running at once.
+#[cfg(test)]
+fn colors(enabled: bool) -> std::sync::MutexGuard<'static, ()> {
+    static COLORS: std::sync::Mutex<()> = std::sync::Mutex::new(());
+    let guard = COLORS.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+    colored::control::set_override(enabled);
+    guard
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_shared::registry::Part;
+    use aoc_shared::scaffold::register;
+    use super::{DAYS, YEAR};
+
+    #[test]
+    fn days_are_registered_in_order() {
+        let days: Vec<u8> = DAYS.iter().map(|day| day.day).collect();
+        assert!(days.windows(2).all(|pair| pair[0] < pair[1]), "{:?}", days);
+    }
+
+    #[test]
+    fn erased_day_test() {
+        let parsed = YEAR.find(6).unwrap().parse("Time:      7  15   30
+Distance:  9  40  200").unwrap();
+        assert_eq!(parsed.part(Part::One).unwrap(), "288");
+        assert_eq!(parsed.part(Part::Two).unwrap(), "71503");
+    }
+
+    #[test]
+    fn erased_error_test() {
+        let error = YEAR.find(6).unwrap().parse("Time:      7  15   30").err().unwrap();
+        assert_eq!(error.day, Some(6));
+        assert_eq!(error.line, 2);
+    }
+
+    #[test]
+    fn register_real_lib_test() {
+        let registered = register(include_str!("lib.rs"), 25).unwrap();
+        assert!(registered.contains("pub mod a25;"));
+        assert!(registered.contains("Day::new::<a25::Day25>(),\n];"));
+    }
+}
@@ -0,0 +1,37 @@
+use crate::parse::ParseError;
+use crate::Solution;
+
+pub struct DayX;
+
+impl Solution for DayX {
+    const DAY: u8 = 0;
+
+    type Input = ();
+    type Answer1 = isize;
+    type Answer2 = isize;
+
+    fn parse(_input: &str) -> Result<Self::Input, ParseError> {
+        Ok(())
+    }
+
+    fn part_1(_input: &Self::Input) -> isize {
+        0
+    }
+
+    fn part_2(_input: &Self::Input) -> isize {
+        0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Solution;
+    use super::DayX;
+
+    const INPUT: &str = "";
+
+    #[test]
+    fn parse_test() {
+        assert_eq!(DayX::parse(INPUT), Ok(()));
+    }
+}
@@ -0,0 +1,163 @@
+use std::env;
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::registry::Part;
+use crate::FIRST_YEAR;
+
+/// Environment variable overriding where the known answers are stored.
+pub const ANSWERS_FILE_VAR: &str = "AOC_ANSWERS_FILE";
+
+pub const DEFAULT_ANSWERS_FILE: &str = repository_path!("answers.toml");
+
+/// FNV-1a, stable across builds and platforms unlike `DefaultHasher`, fed piece by piece.
+#[derive(Debug, Clone)]
+pub struct InputHasher(u64);
+
+impl Default for InputHasher {
+    fn default() -> Self {
+        InputHasher(0xcbf29ce484222325)
+    }
+}
+
+impl InputHasher {
+    pub fn update(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 ^= byte as u64;
+            self.0 = self.0.wrapping_mul(0x100000001b3);
+        }
+    }
+
+    pub fn finish(&self) -> String {
+        format!("{:016x}", self.0)
+    }
+}
+
+/// FNV-1a of the input, see [`InputHasher`].
+pub fn input_hash(input: &str) -> String {
+    let mut hasher = InputHasher::default();
+    hasher.update(input.as_bytes());
+    hasher.finish()
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct KnownAnswer {
+    #[serde(default = "first_year")]
+    year: u16,
+    day: u8,
+    part: u8,
+    input: String,
+    answer: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Verdict {
+    Correct,
+    /// A regression, the known answer differs.
+    Wrong { expected: String },
+    Unknown,
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::Wrong { expected } => write!(f, "WRONG, expected {}", expected),
+            Verdict::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+fn first_year() -> u16 {
+    FIRST_YEAR
+}
+
+/// Answers known to be right for the real inputs, keyed by year, day, part and input hash.
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Answers {
+    #[serde(default, rename = "answer")]
+    answers: Vec<KnownAnswer>,
+}
+
+impl Answers {
+    fn find(&self, year: u16, day: u8, part: Part, input_hash: &str) -> Option<&KnownAnswer> {
+        self.answers.iter().find(
+            |known| known.year == year && known.day == day && known.part == part as u8 && known.input == input_hash
+        )
+    }
+
+    pub fn check(&self, year: u16, day: u8, part: Part, input_hash: &str, answer: &str) -> Verdict {
+        match self.find(year, day, part, input_hash) {
+            Some(known) if known.answer == answer => Verdict::Correct,
+            Some(known) => Verdict::Wrong { expected: known.answer.clone() },
+            None => Verdict::Unknown,
+        }
+    }
+
+    /// Remembers the answer unless one is already known, returns whether it was added.
+    pub fn record(&mut self, year: u16, day: u8, part: Part, input_hash: &str, answer: &str) -> bool {
+        if self.find(year, day, part, input_hash).is_some() {
+            return false;
+        }
+        let known = KnownAnswer { year, day, part: part as u8, input: input_hash.to_string(), answer: answer.to_string() };
+        self.answers.push(known);
+        self.answers.sort_by(|a, b| (a.year, a.day, a.part, &a.input).cmp(&(b.year, b.day, b.part, &b.input)));
+        true
+    }
+}
+
+/// An explicit path wins over [`ANSWERS_FILE_VAR`] and the default file in the repository.
+pub fn resolve(explicit: Option<&Path>) -> PathBuf {
+    match explicit {
+        Some(path) => path.to_path_buf(),
+        None => env::var_os(ANSWERS_FILE_VAR).map(PathBuf::from).unwrap_or_else(|| PathBuf::from(DEFAULT_ANSWERS_FILE)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::registry::Part;
+    use super::{input_hash, Answers, InputHasher, Verdict};
+
+    #[test]
+    fn input_hash_test() {
+        assert_eq!(input_hash(""), "cbf29ce484222325");
+        assert_eq!(input_hash("a"), "af63dc4c8601ec8c");
+        assert_ne!(input_hash("1abc2"), input_hash("1abc3"));
+        let mut hasher = InputHasher::default();
+        hasher.update(b"1ab");
+        hasher.update(b"c2");
+        assert_eq!(hasher.finish(), input_hash("1abc2"));
+    }
+
+    #[test]
+    fn check_test() {
+        let mut answers = Answers::default();
+        assert_eq!(answers.check(2023, 5, Part::One, "abc", "35"), Verdict::Unknown);
+        assert!(answers.record(2023, 5, Part::One, "abc", "35"));
+        assert!(!answers.record(2023, 5, Part::One, "abc", "36"));
+        assert_eq!(answers.check(2023, 5, Part::One, "abc", "35"), Verdict::Correct);
+        assert_eq!(answers.check(2023, 5, Part::One, "abc", "36"), Verdict::Wrong { expected: "35".to_string() });
+        assert_eq!(answers.check(2023, 5, Part::Two, "abc", "35"), Verdict::Unknown);
+        assert_eq!(answers.check(2023, 5, Part::One, "def", "35"), Verdict::Unknown);
+        assert_eq!(answers.check(2024, 5, Part::One, "abc", "35"), Verdict::Unknown);
+    }
+
+    #[test]
+    fn file_format_test() {
+        let content = r#"
+[[answer]]
+day = 8
+part = 2
+input = "0123456789abcdef"
+answer = "13385272668829"
+"#;
+        // without a year, as written before there were more years
+        let answers: Answers = toml::from_str(content).unwrap();
+        assert_eq!(answers.check(2023, 8, Part::Two, "0123456789abcdef", "13385272668829"), Verdict::Correct);
+        assert_eq!(toml::from_str::<Answers>(&toml::to_string(&answers).unwrap()).unwrap(), answers);
+        assert_eq!(toml::from_str::<Answers>("").unwrap(), Answers::default());
+    }
+}
@@ -0,0 +1,212 @@
+use std::fmt;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+
+use crate::log::quietly;
+use crate::parse::ParseError;
+use crate::registry::{Day, Part};
+use crate::settings::Settings;
+use crate::FIRST_YEAR;
+
+pub const DEFAULT_BASELINE_FILE: &str = repository_path!("bench_baseline.toml");
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Phase {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl fmt::Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Phase::Parse => write!(f, "parse"),
+            Phase::Part1 => write!(f, "part 1"),
+            Phase::Part2 => write!(f, "part 2"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BenchOptions {
+    pub warmup: usize,
+    pub iterations: usize,
+}
+
+impl Default for BenchOptions {
+    fn default() -> Self {
+        BenchOptions { warmup: 3, iterations: 20 }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub p90: Duration,
+    pub p99: Duration,
+    pub max: Duration,
+}
+
+/// Nearest rank percentile of sorted samples.
+fn percentile(sorted: &[Duration], percent: usize) -> Duration {
+    let rank = (percent * sorted.len()).div_ceil(100);
+    sorted[rank.saturating_sub(1)]
+}
+
+impl Stats {
+    pub fn from_samples(mut samples: Vec<Duration>) -> Self {
+        assert!(!samples.is_empty(), "at least one sample is needed");
+        samples.sort();
+        Stats {
+            min: samples[0],
+            median: percentile(&samples, 50),
+            p90: percentile(&samples, 90),
+            p99: percentile(&samples, 99),
+            max: samples[samples.len() - 1],
+        }
+    }
+}
+
+/// Runs `f` `warmup` times unmeasured, then measures each of `iterations` runs.
+pub fn measure<T>(options: BenchOptions, mut f: impl FnMut() -> T) -> Stats {
+    for _ in 0..options.warmup {
+        black_box(f());
+    }
+    let samples = (0..options.iterations.max(1))
+        .map(|_| {
+            let start = Instant::now();
+            black_box(f());
+            start.elapsed()
+        })
+        .collect();
+    Stats::from_samples(samples)
+}
+
+/// Times parsing and both parts of the day separately.
+pub fn bench_day(day: &Day, input: &str, settings: &Settings, options: BenchOptions) -> Result<Vec<(Phase, Stats)>, ParseError> {
+    let parsed = day.parse_with(input, settings)?;
+    // a part without an answer would only time its error, and its warnings are shown once here
+    parsed.part(Part::One)?;
+    parsed.part(Part::Two)?;
+    Ok(quietly(|| vec![
+        (Phase::Parse, measure(options, || day.parse_with(input, settings))),
+        (Phase::Part1, measure(options, || parsed.part(Part::One))),
+        (Phase::Part2, measure(options, || parsed.part(Part::Two))),
+    ]))
+}
+
+pub fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos();
+    if nanos < 1_000 {
+        format!("{}ns", nanos)
+    } else if nanos < 1_000_000 {
+        format!("{:.1}µs", nanos as f64 / 1e3)
+    } else if nanos < 1_000_000_000 {
+        format!("{:.1}ms", nanos as f64 / 1e6)
+    } else {
+        format!("{:.2}s", nanos as f64 / 1e9)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct BaselineEntry {
+    #[serde(default = "first_year")]
+    year: u16,
+    day: u8,
+    phase: Phase,
+    median_ns: u64,
+}
+
+fn first_year() -> u16 {
+    FIRST_YEAR
+}
+
+/// Medians of a previous benchmark run to compare against.
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Baseline {
+    #[serde(default, rename = "bench")]
+    entries: Vec<BaselineEntry>,
+}
+
+impl Baseline {
+    pub fn median(&self, year: u16, day: u8, phase: Phase) -> Option<Duration> {
+        self.entries
+            .iter()
+            .find(|entry| entry.year == year && entry.day == day && entry.phase == phase)
+            .map(|entry| Duration::from_nanos(entry.median_ns))
+    }
+
+    pub fn set(&mut self, year: u16, day: u8, phase: Phase, median: Duration) {
+        let median_ns = median.as_nanos() as u64;
+        match self.entries.iter_mut().find(|entry| entry.year == year && entry.day == day && entry.phase == phase) {
+            Some(entry) => entry.median_ns = median_ns,
+            None => self.entries.push(BaselineEntry { year, day, phase, median_ns }),
+        }
+    }
+
+    /// Relative change of the median in percent, positive when slower.
+    pub fn change(&self, year: u16, day: u8, phase: Phase, median: Duration) -> Option<f64> {
+        let baseline = self.median(year, day, phase)?;
+        if baseline.is_zero() {
+            return None;
+        }
+        Some((median.as_secs_f64() / baseline.as_secs_f64() - 1.0) * 100.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::settings::Settings;
+    use crate::test_day::SUM;
+    use super::{bench_day, format_duration, Baseline, BenchOptions, Phase, Stats};
+
+    #[test]
+    fn stats_test() {
+        let stats = Stats::from_samples((1..=100).rev().map(Duration::from_millis).collect());
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_millis(50));
+        assert_eq!(stats.p90, Duration::from_millis(90));
+        assert_eq!(stats.p99, Duration::from_millis(99));
+        assert_eq!(stats.max, Duration::from_millis(100));
+        let single = Stats::from_samples(vec![Duration::from_millis(3)]);
+        assert_eq!((single.median, single.p99), (Duration::from_millis(3), Duration::from_millis(3)));
+    }
+
+    #[test]
+    fn bench_day_test() {
+        let options = BenchOptions { warmup: 1, iterations: 3 };
+        let phases = bench_day(&SUM, "1\n2\n3", &Settings::default(), options).unwrap();
+        assert_eq!(phases.iter().map(|(phase, _)| *phase).collect::<Vec<_>>(), vec![Phase::Parse, Phase::Part1, Phase::Part2]);
+        assert!(bench_day(&SUM, "1\nx", &Settings::default(), options).is_err());
+    }
+
+    #[test]
+    fn baseline_test() {
+        let mut baseline = Baseline::default();
+        assert_eq!(baseline.change(2023, 5, Phase::Part2, Duration::from_millis(3)), None);
+        baseline.set(2023, 5, Phase::Part2, Duration::from_millis(2));
+        baseline.set(2023, 5, Phase::Part2, Duration::from_millis(4));
+        assert_eq!(baseline.change(2023, 5, Phase::Part2, Duration::from_millis(5)), Some(25.0));
+        assert_eq!(baseline.change(2023, 5, Phase::Part1, Duration::from_millis(5)), None);
+        assert_eq!(baseline.change(2024, 5, Phase::Part2, Duration::from_millis(5)), None);
+        let saved = toml::to_string(&baseline).unwrap();
+        assert_eq!(saved, "[[bench]]\nyear = 2023\nday = 5\nphase = \"part2\"\nmedian_ns = 4000000\n");
+        assert_eq!(toml::from_str::<Baseline>(&saved).unwrap(), baseline);
+        let old = "[[bench]]\nday = 5\nphase = \"part2\"\nmedian_ns = 4000000\n";
+        assert_eq!(toml::from_str::<Baseline>(old).unwrap(), baseline);
+    }
+
+    #[test]
+    fn format_duration_test() {
+        assert_eq!(format_duration(Duration::from_nanos(999)), "999ns");
+        assert_eq!(format_duration(Duration::from_nanos(12_345)), "12.3µs");
+        assert_eq!(format_duration(Duration::from_micros(4_560)), "4.6ms");
+        assert_eq!(format_duration(Duration::from_millis(2_345)), "2.35s");
+    }
+}
@@ -0,0 +1,523 @@
+use std::path::PathBuf;
+
+use crate::bench::BenchOptions;
+use crate::generate::Knobs;
+use crate::log::Level;
+use crate::registry::Part;
+use crate::report::Format;
+
+pub const USAGE: &str = "usage: aoc [-q|-v...] [--year <year>] [--set <key>=<value>...] <command>
+
+Diagnostics go to stderr, only warnings by default. -v adds progress of long loops,
+-vv debug messages, -q keeps only errors. Commands work on the latest year unless
+--year picks another one. --set changes how days of the year solve, in 2023
+vocabulary=<english|czech|german|path> picks the number words of day 1, a word list has
+lines of <value>: <word>... tokens=<digits|extended> makes day 1 also read numbers of
+several digits, words above nine like twentyone and Roman numerals, and joins the digits of
+the first and the last one. digitless=<zero|skip|error> decides whether a line of day 1
+without any counts as 0 with a warning, is also left blank in the visualization or fails the
+part. Answers change with the settings, so once one is not the default run neither checks
+nor records them and submit refuses. Like the flags above it can be anywhere.
+
+       aoc run <day>|--all [--part <1|2>] [--input <path>|-] [--answers <path>] [--record]
+                      [--format <text|json|csv>] [--visualize] [--stream|--mmap]
+
+The input defaults to $AOC_INPUT_DIR/<year>/dayNN.txt, or inputs/<year>/dayNN.txt in the repository.
+Answers are checked against $AOC_ANSWERS_FILE, or answers.toml in the repository,
+--record stores the answers not known yet. The json and csv formats hold
+day, part, answer, input hash and duration_ns of each part. --all runs every day with an input
+in parallel and prints a summary table instead of the answers. --visualize draws the parsed
+input of days that can, like the schematic of day 3 or the chosen tokens and calibrations of
+every line of day 1, to stderr. --stream reads the input line by line instead of all at once,
+for days that can, so inputs of any size fit in memory. --mmap maps an input file into memory
+and solves line-aligned chunks of it on all cores, for days that can, for inputs of gigabytes.
+
+       aoc bench [<day>...] [--input <path>|-] [--warmup <n>] [--iterations <n>]
+                 [--baseline <path>] [--save] [--threshold <percent>]
+
+Without days every day with an input is benchmarked. Medians are compared with the baseline,
+bench_baseline.toml in the repository by default, and --save stores them as the new baseline.
+
+       aoc profile [<day>...] [--input <path>|-]
+
+Counts allocations, the bytes allocated and the peak of live memory while parsing and in each
+part, once per day. Without days every day with an input is profiled.
+
+       aoc new <day>
+
+Creates <year>/src/a<day>.rs from the shared/src/aX.rs template, registers it in <year>/src/lib.rs
+and adds an empty example with its answers in <year>/examples/dayNN/.
+
+       aoc fetch <day>...
+
+Downloads inputs into the input directory unless they are there already. The session token
+comes from $AOC_SESSION or aoc.toml in the repository, which can also set base_url
+and throttle_seconds.
+
+       aoc submit <day> <1|2> [<answer>] [--input <path>|-] [--submissions <path>]
+
+Posts the answer, solving the day when it is not given. Verdicts are remembered in
+$AOC_SUBMISSIONS_FILE, or submissions.toml in the repository, and answers known to be wrong,
+or beyond one that was too high or too low, are not sent again. Solved answers are recorded
+in the answers file.
+
+       aoc generate <day> [--seed <n>] [--size <n>] [--width <n>] [--ghosts <n>] [--maps <n>]
+
+Prints a synthetic input, the same for the same knobs. --size sets the number of lines, games,
+rows, cards, ranges per map, races, hands or histories, --width the characters of a line,
+columns, winning numbers, navigation length or values of a history. In 2023 day 8 has --ghosts
+and day 5 --maps.";
+
+#[derive(Debug, PartialEq)]
+pub struct Args {
+    pub level: Level,
+    /// The latest year when not given.
+    pub year: Option<u16>,
+    /// Keys and values of `--set`, in order.
+    pub settings: Vec<(String, String)>,
+    pub command: Command,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Command {
+    Run(RunArgs),
+    Bench(BenchArgs),
+    Profile(ProfileArgs),
+    New { day: u8 },
+    Fetch { days: Vec<u8> },
+    Submit(SubmitArgs),
+    Generate { day: u8, knobs: Knobs },
+}
+
+#[derive(Debug, PartialEq)]
+pub struct RunArgs {
+    /// Every registered day when `None`, given by `--all`.
+    pub day: Option<u8>,
+    /// Both parts when not given.
+    pub part: Option<Part>,
+    /// `-` for stdin, see [`crate::input::resolve`].
+    pub input: Option<PathBuf>,
+    /// See [`crate::answers::resolve`].
+    pub answers: Option<PathBuf>,
+    pub record: bool,
+    pub format: Format,
+    pub visualize: bool,
+    pub stream: bool,
+    pub mmap: bool,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct BenchArgs {
+    /// All days when empty.
+    pub days: Vec<u8>,
+    /// Only for a single day.
+    pub input: Option<PathBuf>,
+    pub options: BenchOptions,
+    pub baseline: Option<PathBuf>,
+    pub save: bool,
+    /// Slowdown against the baseline in percent that gets flagged.
+    pub threshold: f64,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct ProfileArgs {
+    /// All days when empty.
+    pub days: Vec<u8>,
+    /// Only for a single day.
+    pub input: Option<PathBuf>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct SubmitArgs {
+    pub day: u8,
+    pub part: Part,
+    /// Computed from the input when not given.
+    pub answer: Option<String>,
+    pub input: Option<PathBuf>,
+    /// See [`crate::submit::resolve`].
+    pub submissions: Option<PathBuf>,
+}
+
+fn value(args: &mut impl Iterator<Item=String>, flag: &str) -> Result<String, String> {
+    args.next().ok_or_else(|| format!("{} needs a value", flag))
+}
+
+fn parse_run(mut args: impl Iterator<Item=String>) -> Result<RunArgs, String> {
+    let mut day = None;
+    let mut all = false;
+    let mut part = None;
+    let mut input = None;
+    let mut answers = None;
+    let mut record = false;
+    let mut format = Format::default();
+    let mut visualize = false;
+    let mut stream = false;
+    let mut mmap = false;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--part" | "-p" => part = Some(value(&mut args, &arg)?.parse()?),
+            "--input" | "-i" => input = Some(PathBuf::from(value(&mut args, &arg)?)),
+            "--answers" => answers = Some(PathBuf::from(value(&mut args, &arg)?)),
+            "--record" => record = true,
+            "--all" | "-a" => all = true,
+            "--visualize" => visualize = true,
+            "--stream" => stream = true,
+            "--mmap" => mmap = true,
+            "--format" | "-f" => format = value(&mut args, &arg)?.parse()?,
+            _ if day.is_none() => {
+                day = Some(arg.parse().ok().filter(|day| (1..=25).contains(day)).ok_or_else(|| format!("invalid day {:?}", arg))?)
+            }
+            _ => return Err(format!("unexpected argument {:?}", arg)),
+        }
+    }
+    match (day, all) {
+        (None, false) => return Err("missing day".to_string()),
+        (Some(_), true) => return Err("--all takes no day".to_string()),
+        (None, true) if input.is_some() => return Err("--input needs a day".to_string()),
+        (None, true) if visualize => return Err("--visualize needs a day".to_string()),
+        (None, true) if stream => return Err("--stream needs a day".to_string()),
+        (None, true) if mmap => return Err("--mmap needs a day".to_string()),
+        _ => {}
+    }
+    if stream && visualize {
+        return Err("--visualize needs the whole input, not a stream".to_string());
+    }
+    if mmap && (stream || visualize) {
+        return Err("--mmap goes with neither --stream nor --visualize".to_string());
+    }
+    Ok(RunArgs { day, part, input, answers, record, format, visualize, stream, mmap })
+}
+
+fn parse_bench(mut args: impl Iterator<Item=String>) -> Result<BenchArgs, String> {
+    let mut bench = BenchArgs {
+        days: vec![],
+        input: None,
+        options: BenchOptions::default(),
+        baseline: None,
+        save: false,
+        threshold: 10.0,
+    };
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--input" | "-i" => bench.input = Some(PathBuf::from(value(&mut args, &arg)?)),
+            "--warmup" => bench.options.warmup = value(&mut args, &arg)?.parse().map_err(|_| "invalid warmup")?,
+            "--iterations" => bench.options.iterations = value(&mut args, &arg)?.parse().map_err(|_| "invalid iterations")?,
+            "--baseline" => bench.baseline = Some(PathBuf::from(value(&mut args, &arg)?)),
+            "--save" => bench.save = true,
+            "--threshold" => bench.threshold = value(&mut args, &arg)?.parse().map_err(|_| "invalid threshold")?,
+            _ => bench.days.push(arg.parse().map_err(|_| format!("invalid day {:?}", arg))?),
+        }
+    }
+    if bench.input.is_some() && bench.days.len() != 1 {
+        return Err("--input needs exactly one day".to_string());
+    }
+    if bench.options.iterations == 0 {
+        return Err("at least one iteration is needed".to_string());
+    }
+    Ok(bench)
+}
+
+fn parse_profile(mut args: impl Iterator<Item=String>) -> Result<ProfileArgs, String> {
+    let mut profile = ProfileArgs { days: vec![], input: None };
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--input" | "-i" => profile.input = Some(PathBuf::from(value(&mut args, &arg)?)),
+            _ => profile.days.push(arg.parse().map_err(|_| format!("invalid day {:?}", arg))?),
+        }
+    }
+    if profile.input.is_some() && profile.days.len() != 1 {
+        return Err("--input needs exactly one day".to_string());
+    }
+    Ok(profile)
+}
+
+fn parse_submit(mut args: impl Iterator<Item=String>) -> Result<SubmitArgs, String> {
+    let mut positional = vec![];
+    let mut input = None;
+    let mut submissions = None;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--input" | "-i" => input = Some(PathBuf::from(value(&mut args, &arg)?)),
+            "--submissions" => submissions = Some(PathBuf::from(value(&mut args, &arg)?)),
+            _ => positional.push(arg),
+        }
+    }
+    let mut positional = positional.into_iter();
+    let day = positional.next().ok_or("missing day")?;
+    let day = day.parse().ok().filter(|day| (1..=25).contains(day)).ok_or_else(|| format!("invalid day {:?}", day))?;
+    let part = positional.next().ok_or("missing part")?.parse()?;
+    let answer = positional.next();
+    if let Some(arg) = positional.next() {
+        return Err(format!("unexpected argument {:?}", arg));
+    }
+    if answer.is_some() && input.is_some() {
+        return Err("--input is only used to compute the answer".to_string());
+    }
+    Ok(SubmitArgs { day, part, answer, input, submissions })
+}
+
+fn parse_generate(mut args: impl Iterator<Item=String>) -> Result<Command, String> {
+    let mut day = None;
+    let mut knobs = Knobs::default();
+    while let Some(arg) = args.next() {
+        let mut number = |name: &str| -> Result<usize, String> {
+            value(&mut args, &arg)?.parse().map_err(|_| format!("invalid {}", name))
+        };
+        match arg.as_str() {
+            "--seed" => knobs.seed = value(&mut args, &arg)?.parse().map_err(|_| "invalid seed")?,
+            "--size" => knobs.size = Some(number("size")?),
+            "--width" => knobs.width = Some(number("width")?),
+            "--ghosts" => knobs.ghosts = Some(number("ghosts")?),
+            "--maps" => knobs.maps = Some(number("maps")?),
+            _ if day.is_none() => day = Some(arg.parse().map_err(|_| format!("invalid day {:?}", arg))?),
+            _ => return Err(format!("unexpected argument {:?}", arg)),
+        }
+    }
+    let day = day.ok_or("missing day")?;
+    Ok(Command::Generate { day, knobs })
+}
+
+fn parse_days(args: impl Iterator<Item=String>) -> Result<Vec<u8>, String> {
+    let days = args
+        .map(|arg| arg.parse().ok().filter(|day| (1..=25).contains(day)).ok_or_else(|| format!("invalid day {:?}", arg)))
+        .collect::<Result<Vec<u8>, _>>()?;
+    if days.is_empty() {
+        return Err("missing day".to_string());
+    }
+    Ok(days)
+}
+
+fn parse_command(mut args: impl Iterator<Item=String>) -> Result<Command, String> {
+    match args.next().as_deref() {
+        Some("run") => Ok(Command::Run(parse_run(args)?)),
+        Some("bench") => Ok(Command::Bench(parse_bench(args)?)),
+        Some("profile") => Ok(Command::Profile(parse_profile(args)?)),
+        Some("new") => match parse_days(args)?[..] {
+            [day] => Ok(Command::New { day }),
+            _ => Err("new takes exactly one day".to_string()),
+        },
+        Some("fetch") => Ok(Command::Fetch { days: parse_days(args)? }),
+        Some("submit") => Ok(Command::Submit(parse_submit(args)?)),
+        Some("generate") => parse_generate(args),
+        Some(command) => Err(format!("unknown command {:?}", command)),
+        None => Err("missing command".to_string()),
+    }
+}
+
+/// Parses command line arguments without the program name, verbosity flags, the year and settings can be anywhere.
+pub fn parse_args(mut args: impl Iterator<Item=String>) -> Result<Args, String> {
+    let mut verbosity: i8 = 0;
+    let mut year = None;
+    let mut settings = vec![];
+    let mut rest = vec![];
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-q" | "--quiet" => verbosity = -1,
+            "--year" | "-y" => {
+                let value = value(&mut args, &arg)?;
+                year = Some(value.parse().map_err(|_| format!("invalid year {:?}", value))?);
+            }
+            "--set" => {
+                let setting = value(&mut args, &arg)?;
+                let (key, value) = setting.split_once('=').ok_or_else(|| format!("--set needs <key>=<value>, not {:?}", setting))?;
+                settings.push((key.to_string(), value.to_string()));
+            }
+            "--verbose" => verbosity = verbosity.saturating_add(1),
+            _ if arg.len() > 1 && arg.starts_with('-') && arg[1..].bytes().all(|byte| byte == b'v') => {
+                verbosity = verbosity.saturating_add(arg.len() as i8 - 1);
+            }
+            _ => rest.push(arg),
+        }
+    }
+    Ok(Args { level: Level::from_verbosity(verbosity), year, settings, command: parse_command(rest.into_iter())? })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use crate::bench::BenchOptions;
+    use crate::generate::Knobs;
+    use crate::log::Level;
+    use crate::registry::Part;
+    use crate::report::Format;
+    use super::{parse_args, BenchArgs, Command, ProfileArgs, RunArgs, SubmitArgs};
+
+    fn parse(args: &str) -> Result<Command, String> {
+        parse_args(args.split_whitespace().map(String::from)).map(|args| args.command)
+    }
+
+    fn level(args: &str) -> Level {
+        parse_args(args.split_whitespace().map(String::from)).unwrap().level
+    }
+
+    #[test]
+    fn run_test() {
+        assert_eq!(
+            parse("run 5 --part 2 --input path/input5.txt --answers answers.toml --record --format json --visualize"),
+            Ok(Command::Run(RunArgs {
+                day: Some(5),
+                part: Some(Part::Two),
+                input: Some(PathBuf::from("path/input5.txt")),
+                answers: Some(PathBuf::from("answers.toml")),
+                record: true,
+                format: Format::Json,
+                visualize: true,
+                stream: false,
+                mmap: false,
+            }))
+        );
+        assert_eq!(
+            parse("run 12"),
+            Ok(Command::Run(RunArgs {
+                day: Some(12),
+                part: None,
+                input: None,
+                answers: None,
+                record: false,
+                format: Format::Text,
+                visualize: false,
+                stream: false,
+                mmap: false,
+            }))
+        );
+        assert!(matches!(parse("run 1 --stream --input -"), Ok(Command::Run(RunArgs { stream: true, .. }))));
+        assert!(matches!(parse("run --all --part 1"), Ok(Command::Run(RunArgs { day: None, part: Some(Part::One), .. }))));
+        assert!(parse("run 5 --all").is_err());
+        assert!(parse("run --all --input input.txt").is_err());
+        assert!(parse("run --all --visualize").is_err());
+        assert!(parse("run --all --stream").is_err());
+        assert!(parse("run 3 --stream --visualize").is_err());
+        assert!(matches!(parse("run 1 --mmap --input big.txt"), Ok(Command::Run(RunArgs { mmap: true, .. }))));
+        assert!(parse("run --all --mmap").is_err());
+        assert!(parse("run 1 --mmap --stream").is_err());
+    }
+
+    #[test]
+    fn bench_test() {
+        assert_eq!(
+            parse("bench 5 8 --iterations 50 --warmup 0 --save --threshold 5"),
+            Ok(Command::Bench(BenchArgs {
+                days: vec![5, 8],
+                input: None,
+                options: BenchOptions { warmup: 0, iterations: 50 },
+                baseline: None,
+                save: true,
+                threshold: 5.0,
+            }))
+        );
+        assert!(matches!(parse("bench"), Ok(Command::Bench(BenchArgs { days, .. })) if days.is_empty()));
+        assert!(parse("bench --input input.txt").is_err());
+        assert!(parse("bench 5 --iterations 0").is_err());
+    }
+
+    #[test]
+    fn profile_test() {
+        assert_eq!(parse("profile 4 8"), Ok(Command::Profile(ProfileArgs { days: vec![4, 8], input: None })));
+        assert_eq!(
+            parse("profile 8 --input big.txt"),
+            Ok(Command::Profile(ProfileArgs { days: vec![8], input: Some(PathBuf::from("big.txt")) }))
+        );
+        assert!(matches!(parse("profile"), Ok(Command::Profile(ProfileArgs { days, .. })) if days.is_empty()));
+        assert!(parse("profile --input big.txt").is_err());
+        assert!(parse("profile eight").is_err());
+    }
+
+    #[test]
+    fn new_test() {
+        assert_eq!(parse("new 10"), Ok(Command::New { day: 10 }));
+    }
+
+    #[test]
+    fn fetch_test() {
+        assert_eq!(parse("fetch 1 2 25"), Ok(Command::Fetch { days: vec![1, 2, 25] }));
+        assert!(parse("fetch").is_err());
+        assert!(parse("fetch 0").is_err());
+    }
+
+    #[test]
+    fn submit_test() {
+        assert_eq!(
+            parse("submit 6 2 71503 --submissions submissions.toml"),
+            Ok(Command::Submit(SubmitArgs {
+                day: 6,
+                part: Part::Two,
+                answer: Some("71503".to_string()),
+                input: None,
+                submissions: Some(PathBuf::from("submissions.toml")),
+            }))
+        );
+        assert_eq!(
+            parse("submit 6 1 --input -"),
+            Ok(Command::Submit(SubmitArgs { day: 6, part: Part::One, answer: None, input: Some(PathBuf::from("-")), submissions: None }))
+        );
+        assert!(parse("submit 6").is_err());
+        assert!(parse("submit 6 3 1").is_err());
+        assert!(parse("submit 6 1 1 2").is_err());
+        assert!(parse("submit 6 1 1 --input -").is_err());
+    }
+
+    #[test]
+    fn generate_test() {
+        assert_eq!(
+            parse("generate 8 --seed 42 --width 263 --ghosts 6"),
+            Ok(Command::Generate { day: 8, knobs: Knobs { seed: 42, size: None, width: Some(263), ghosts: Some(6), maps: None } })
+        );
+        assert_eq!(parse("generate 7 --size 1000000"), Ok(Command::Generate {
+            day: 7,
+            knobs: Knobs { size: Some(1_000_000), ..Knobs::default() },
+        }));
+        assert!(parse("generate").is_err());
+        assert!(parse("generate 7 --size -1").is_err());
+    }
+
+    #[test]
+    fn verbosity_test() {
+        assert_eq!(level("run 8"), Level::Warn);
+        assert_eq!(level("-v run 8"), Level::Info);
+        assert_eq!(level("run 8 -vv"), Level::Debug);
+        assert_eq!(level("-v run 8 --verbose -v"), Level::Trace);
+        assert_eq!(level("run 8 -q"), Level::Error);
+        assert_eq!(parse("-vv run 8 --part 2"), parse("run 8 --part 2"));
+    }
+
+    #[test]
+    fn year_test() {
+        let year = |args: &str| parse_args(args.split_whitespace().map(String::from)).map(|args| args.year);
+        assert_eq!(year("run 8"), Ok(None));
+        assert_eq!(year("--year 2022 run 8"), Ok(Some(2022)));
+        assert_eq!(year("run 8 -y 2024 --part 2"), Ok(Some(2024)));
+        assert_eq!(parse("run 8 -y 2024 --part 2"), parse("run 8 --part 2"));
+        assert!(year("run 8 --year").is_err());
+        assert!(year("run 8 --year last").is_err());
+    }
+
+    #[test]
+    fn settings_test() {
+        let settings = |args: &str| parse_args(args.split_whitespace().map(String::from)).map(|args| args.settings);
+        assert_eq!(settings("run 1"), Ok(vec![]));
+        assert_eq!(
+            settings("--set vocabulary=czech run 1 --set x=a=b"),
+            Ok(vec![("vocabulary".to_string(), "czech".to_string()), ("x".to_string(), "a=b".to_string())])
+        );
+        assert_eq!(parse("run 1 --set vocabulary=czech"), parse("run 1"));
+        assert!(settings("run 1 --set vocabulary").is_err());
+        assert!(settings("run 1 --set").is_err());
+    }
+
+    #[test]
+    fn invalid_test() {
+        assert!(parse("").is_err());
+        assert!(parse("walk 5").is_err());
+        assert!(parse("run").is_err());
+        assert!(parse("run five").is_err());
+        assert_eq!(parse("run 0"), Err("invalid day \"0\"".to_string()));
+        assert!(parse("run 99").is_err());
+        assert!(parse("run 5 --part 3").is_err());
+        assert!(parse("run 5 --input").is_err());
+        assert!(parse("run 5 6").is_err());
+        assert!(parse("run 5 --format xml").is_err());
+        assert!(parse("new 26").is_err());
+        assert!(parse("new 10 11").is_err());
+    }
+}
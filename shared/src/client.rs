@@ -0,0 +1,194 @@
+use std::fmt;
+use std::fs::{create_dir_all, read_to_string, write};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::thread::sleep;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::config::Config;
+
+const USER_AGENT: &str = "github.com/jancespivo/aoc";
+
+#[derive(Debug)]
+pub enum ClientError {
+    MissingSession,
+    Status { status: u16, body: String },
+    Transport(String),
+    Io(io::Error),
+}
+
+impl fmt::Display for ClientError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ClientError::MissingSession => write!(f, "no session token, set AOC_SESSION or session in aoc.toml"),
+            ClientError::Status { status, body } => write!(f, "server responded {}: {}", status, body.trim()),
+            ClientError::Transport(err) => write!(f, "request failed: {}", err),
+            ClientError::Io(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for ClientError {}
+
+impl From<io::Error> for ClientError {
+    fn from(err: io::Error) -> Self {
+        ClientError::Io(err)
+    }
+}
+
+impl From<ureq::Error> for ClientError {
+    fn from(err: ureq::Error) -> Self {
+        match err {
+            ureq::Error::Status(status, response) => ClientError::Status {
+                status,
+                body: response.into_string().unwrap_or_default(),
+            },
+            ureq::Error::Transport(transport) => ClientError::Transport(transport.to_string()),
+        }
+    }
+}
+
+/// Keeps requests at least `interval` apart, also across runs, by remembering the last one in a file.
+#[derive(Debug, Clone)]
+pub struct Throttle {
+    pub path: PathBuf,
+    pub interval: Duration,
+}
+
+impl Throttle {
+    fn now() -> Duration {
+        SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default()
+    }
+
+    pub fn wait(&self) -> io::Result<()> {
+        let last = read_to_string(&self.path).ok().and_then(|millis| millis.trim().parse().ok()).map(Duration::from_millis);
+        if let Some(remaining) = last.and_then(|last| (last + self.interval).checked_sub(Self::now())) {
+            sleep(remaining);
+        }
+        if let Some(parent) = self.path.parent() {
+            create_dir_all(parent)?;
+        }
+        write(&self.path, Self::now().as_millis().to_string())
+    }
+}
+
+pub struct Client {
+    base_url: String,
+    session: Option<String>,
+    throttle: Throttle,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    pub fn new(config: &Config, throttle_file: PathBuf) -> Self {
+        Client {
+            base_url: config.base_url().to_string(),
+            session: config.session.clone(),
+            throttle: Throttle { path: throttle_file, interval: config.throttle() },
+            agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build(),
+        }
+    }
+
+    fn url(&self, year: u16, day: u8, path: &str) -> String {
+        format!("{}/{}/day/{}{}", self.base_url, year, day, path)
+    }
+
+    fn cookie(&self) -> Result<String, ClientError> {
+        let session = self.session.as_deref().ok_or(ClientError::MissingSession)?;
+        Ok(format!("session={}", session))
+    }
+
+    pub fn get(&self, year: u16, day: u8, path: &str) -> Result<String, ClientError> {
+        let cookie = self.cookie()?;
+        self.throttle.wait()?;
+        Ok(self.agent.get(&self.url(year, day, path)).set("Cookie", &cookie).call()?.into_string()?)
+    }
+
+    pub fn post_form(&self, year: u16, day: u8, path: &str, form: &[(&str, &str)]) -> Result<String, ClientError> {
+        let cookie = self.cookie()?;
+        self.throttle.wait()?;
+        Ok(self.agent.post(&self.url(year, day, path)).set("Cookie", &cookie).send_form(form)?.into_string()?)
+    }
+
+    /// Downloads the input of the day into `cache` unless it is there already, returns whether it did.
+    pub fn fetch_input(&self, year: u16, day: u8, cache: &Path) -> Result<bool, ClientError> {
+        if cache.exists() {
+            return Ok(false);
+        }
+        let input = self.get(year, day, "/input")?;
+        if let Some(parent) = cache.parent() {
+            create_dir_all(parent)?;
+        }
+        write(cache, input)?;
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env::temp_dir;
+    use std::fs::{read_to_string, remove_dir_all};
+    use std::path::PathBuf;
+    use std::time::{Duration, Instant};
+
+    use crate::config::Config;
+    use crate::test_server::serve;
+    use super::{Client, ClientError, Throttle};
+
+    fn scratch_dir(name: &str) -> PathBuf {
+        let dir = temp_dir().join(format!("aoc-client-{}-{}", name, std::process::id()));
+        let _ = remove_dir_all(&dir);
+        dir
+    }
+
+    fn client(base_url: &str, dir: &std::path::Path) -> Client {
+        let config = Config { session: Some("cafe".to_string()), base_url: Some(base_url.to_string()), throttle_seconds: Some(0) };
+        Client::new(&config, dir.join(".last_request"))
+    }
+
+    #[test]
+    fn fetch_input_test() {
+        let dir = scratch_dir("fetch");
+        let (base_url, requests) = serve(vec![(200, "0 3 6 9 12 15\n")]);
+        let client = client(&base_url, &dir);
+        let cache = dir.join("2023/day09.txt");
+        assert!(client.fetch_input(2023, 9, &cache).unwrap());
+        assert_eq!(read_to_string(&cache).unwrap(), "0 3 6 9 12 15\n");
+        // cached, the server would not answer a second request anyway
+        assert!(!client.fetch_input(2023, 9, &cache).unwrap());
+
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests.len(), 1);
+        assert!(requests[0].starts_with("GET /2023/day/9/input HTTP/1.1\r\n"));
+        assert!(requests[0].contains("Cookie: session=cafe\r\n"));
+        remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn fetch_error_test() {
+        let dir = scratch_dir("error");
+        let (base_url, _) = serve(vec![(400, "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n")]);
+        let cache = dir.join("2023/day01.txt");
+        match client(&base_url, &dir).fetch_input(2023, 1, &cache) {
+            Err(ClientError::Status { status: 400, body }) => assert!(body.starts_with("Puzzle inputs differ")),
+            other => panic!("unexpected {:?}", other),
+        }
+        assert!(!cache.exists());
+
+        let config = Config { base_url: Some(base_url), ..Config::default() };
+        let client = Client::new(&config, dir.join(".last_request"));
+        assert!(matches!(client.fetch_input(2023, 1, &cache), Err(ClientError::MissingSession)));
+        let _ = remove_dir_all(dir);
+    }
+
+    #[test]
+    fn throttle_test() {
+        let dir = scratch_dir("throttle");
+        let throttle = Throttle { path: dir.join(".last_request"), interval: Duration::from_millis(200) };
+        throttle.wait().unwrap();
+        let start = Instant::now();
+        throttle.wait().unwrap();
+        assert!(start.elapsed() >= Duration::from_millis(150));
+        remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,69 @@
+use std::env;
+use std::io;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use serde::Deserialize;
+
+use crate::store::load_toml;
+
+/// Environment variable overriding where the config is read from.
+pub const CONFIG_FILE_VAR: &str = "AOC_CONFIG";
+pub const SESSION_VAR: &str = "AOC_SESSION";
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+
+/// Not committed, it holds the session token.
+pub const DEFAULT_CONFIG_FILE: &str = repository_path!("aoc.toml");
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Settings for talking to the puzzle server, from `aoc.toml` overridden by the environment.
+#[derive(Debug, Default, Clone, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    /// Value of the `session` cookie of a logged in browser.
+    pub session: Option<String>,
+    pub base_url: Option<String>,
+    /// Minimal pause between two requests to the server.
+    pub throttle_seconds: Option<u64>,
+}
+
+impl Config {
+    /// Loads the config file and applies [`SESSION_VAR`] and [`BASE_URL_VAR`] on top of it.
+    pub fn from_env() -> io::Result<Self> {
+        let path = env::var_os(CONFIG_FILE_VAR).map(PathBuf::from).unwrap_or_else(|| PathBuf::from(DEFAULT_CONFIG_FILE));
+        let mut config: Config = load_toml(&path)?;
+        if let Ok(session) = env::var(SESSION_VAR) {
+            config.session = Some(session);
+        }
+        if let Ok(base_url) = env::var(BASE_URL_VAR) {
+            config.base_url = Some(base_url);
+        }
+        Ok(config)
+    }
+
+    pub fn base_url(&self) -> &str {
+        self.base_url.as_deref().unwrap_or(DEFAULT_BASE_URL).trim_end_matches('/')
+    }
+
+    pub fn throttle(&self) -> Duration {
+        Duration::from_secs(self.throttle_seconds.unwrap_or(5))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::Config;
+
+    #[test]
+    fn config_test() {
+        let config: Config = toml::from_str("session = \"53616c7465645f5f\"\nbase_url = \"http://localhost:8080/\"\nthrottle_seconds = 1").unwrap();
+        assert_eq!(config.session.as_deref(), Some("53616c7465645f5f"));
+        assert_eq!(config.base_url(), "http://localhost:8080");
+        assert_eq!(config.throttle(), Duration::from_secs(1));
+        assert_eq!(Config::default().base_url(), "https://adventofcode.com");
+        assert!(toml::from_str::<Config>("sesion = \"typo\"").is_err());
+    }
+}
@@ -0,0 +1,55 @@
+//! Building blocks for the generators of synthetic inputs every year provides.
+
+/// SplitMix64, small and stable across platforms and versions unlike the `rand` generators.
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform in `low..=high`, up to a negligible bias.
+    pub fn between(&mut self, low: u64, high: u64) -> u64 {
+        match (high - low).checked_add(1) {
+            Some(span) => low + self.next_u64() % span,
+            None => self.next_u64(),
+        }
+    }
+
+    pub fn chance(&mut self, percent: u64) -> bool {
+        self.between(1, 100) <= percent
+    }
+
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.between(0, items.len() as u64 - 1) as usize]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for index in (1..items.len()).rev() {
+            items.swap(index, self.between(0, index as u64) as usize);
+        }
+    }
+}
+
+/// Scale of a generated input, every day picks its own default for the knobs it reads
+/// and ignores the rest.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Knobs {
+    pub seed: u64,
+    /// Lines, games, rows, cards, ranges per map, races, hands or histories.
+    pub size: Option<usize>,
+    /// Characters of a line, columns, winning numbers, navigation length or values of a history.
+    pub width: Option<usize>,
+    /// Starting nodes of a network, 2023 day 8.
+    pub ghosts: Option<usize>,
+    /// Maps between seeds and locations, 2023 day 5.
+    pub maps: Option<usize>,
+}
@@ -0,0 +1,95 @@
+use std::env;
+use std::fmt;
+use std::fs::{read_to_string, File};
+use std::io::{self, BufRead, BufReader, Read};
+use std::path::{Path, PathBuf};
+
+/// Environment variable overriding the directory with puzzle inputs.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Where the inputs live when [`INPUT_DIR_VAR`] is not set, independent of the working directory.
+pub const DEFAULT_INPUT_DIR: &str = repository_path!("inputs");
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum InputSource {
+    Path(PathBuf),
+    Stdin,
+}
+
+impl fmt::Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            InputSource::Path(path) => write!(f, "{}", path.display()),
+            InputSource::Stdin => write!(f, "stdin"),
+        }
+    }
+}
+
+impl InputSource {
+    pub fn read(&self) -> io::Result<String> {
+        match self {
+            InputSource::Path(path) => read_to_string(path),
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)?;
+                Ok(input)
+            }
+        }
+    }
+
+    /// For reading the input piece by piece instead of all at once.
+    pub fn open(&self) -> io::Result<Box<dyn BufRead>> {
+        match self {
+            InputSource::Path(path) => Ok(Box::new(BufReader::new(File::open(path)?))),
+            InputSource::Stdin => Ok(Box::new(io::stdin().lock())),
+        }
+    }
+}
+
+/// `<input_dir>/2023/day05.txt`
+pub fn day_path(input_dir: &Path, year: u16, day: u8) -> PathBuf {
+    input_dir.join(year.to_string()).join(format!("day{:02}.txt", day))
+}
+
+/// An explicit path wins, `-` means stdin, otherwise the day's file in the input directory.
+pub fn resolve_with(explicit: Option<&Path>, year: u16, day: u8, input_dir: Option<&Path>) -> InputSource {
+    match explicit {
+        Some(path) if path == Path::new("-") => InputSource::Stdin,
+        Some(path) => InputSource::Path(path.to_path_buf()),
+        None => InputSource::Path(day_path(input_dir.unwrap_or(Path::new(DEFAULT_INPUT_DIR)), year, day)),
+    }
+}
+
+/// [`INPUT_DIR_VAR`] or [`DEFAULT_INPUT_DIR`].
+pub fn input_dir() -> PathBuf {
+    env::var_os(INPUT_DIR_VAR).map(PathBuf::from).unwrap_or_else(|| PathBuf::from(DEFAULT_INPUT_DIR))
+}
+
+/// Like [`resolve_with`], taking the input directory from [`input_dir`].
+pub fn resolve(explicit: Option<&Path>, year: u16, day: u8) -> InputSource {
+    resolve_with(explicit, year, day, Some(&input_dir()))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::{Path, PathBuf};
+
+    use super::{resolve_with, InputSource, DEFAULT_INPUT_DIR};
+
+    #[test]
+    fn resolve_test() {
+        assert_eq!(
+            resolve_with(Some(Path::new("my/input.txt")), 2023, 5, Some(Path::new("/aoc"))),
+            InputSource::Path(PathBuf::from("my/input.txt"))
+        );
+        assert_eq!(resolve_with(Some(Path::new("-")), 2023, 5, None), InputSource::Stdin);
+        assert_eq!(
+            resolve_with(None, 2023, 5, Some(Path::new("/aoc"))),
+            InputSource::Path(PathBuf::from("/aoc/2023/day05.txt"))
+        );
+        assert_eq!(
+            resolve_with(None, 2024, 12, None),
+            InputSource::Path(Path::new(DEFAULT_INPUT_DIR).join("2024/day12.txt"))
+        );
+    }
+}
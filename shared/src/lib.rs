@@ -0,0 +1,48 @@
+//! Everything but the solutions: the registry of days, the command line, the stores and the client.
+//! Every year is a crate of its own exporting a [`Year`] for the runner.
+
+/// A path in the repository, independent of the working directory and of the crate it is used in.
+macro_rules! repository_path {
+    ($path:literal) => {
+        concat!(env!("CARGO_MANIFEST_DIR"), "/../", $path)
+    };
+}
+
+pub mod answers;
+pub mod bench;
+pub mod cli;
+pub mod client;
+pub mod config;
+pub mod generate;
+pub mod input;
+pub mod log;
+pub mod mapped;
+pub mod memory;
+pub mod parse;
+pub mod registry;
+pub mod render;
+pub mod report;
+pub mod runner;
+pub mod scaffold;
+pub mod settings;
+pub mod solution;
+pub mod store;
+pub mod stream;
+pub mod submit;
+
+#[cfg(test)]
+mod test_day;
+#[cfg(test)]
+mod test_server;
+
+// the template for a new day used by `aoc new`, compiled only to keep it from rotting
+#[cfg(test)]
+#[path = "aX.rs"]
+mod ax;
+
+pub use parse::ParseError;
+pub use registry::{Day, Year};
+pub use solution::Solution;
+
+/// The year assumed for entries of the stores written before they tracked years.
+pub const FIRST_YEAR: u16 = 2023;
@@ -0,0 +1,180 @@
+//! Leveled diagnostics and progress of long loops, always on stderr so stdout holds only answers.
+
+use std::fmt;
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::time::{Duration, Instant};
+
+use colored::Colorize;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Error = 0,
+    Warn = 1,
+    Info = 2,
+    Debug = 3,
+    Trace = 4,
+}
+
+impl Level {
+    /// `-q` is -1, every `-v` adds one to the default warnings.
+    pub fn from_verbosity(verbosity: i8) -> Self {
+        match verbosity {
+            ..=-1 => Level::Error,
+            0 => Level::Warn,
+            1 => Level::Info,
+            2 => Level::Debug,
+            _ => Level::Trace,
+        }
+    }
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Level::Error => write!(f, "{}", "error".red().bold()),
+            Level::Warn => write!(f, "{}", "warning".yellow()),
+            Level::Info => write!(f, "{}", "info".cyan()),
+            Level::Debug => write!(f, "{}", "debug".dimmed()),
+            Level::Trace => write!(f, "{}", "trace".dimmed()),
+        }
+    }
+}
+
+static LEVEL: AtomicU8 = AtomicU8::new(Level::Warn as u8);
+
+pub fn set_level(level: Level) {
+    LEVEL.store(level as u8, Ordering::Relaxed);
+}
+
+pub fn enabled(level: Level) -> bool {
+    level as u8 <= LEVEL.load(Ordering::Relaxed)
+}
+
+/// Runs `f` with only errors logged, the level set before is back afterwards.
+pub fn quietly<T>(f: impl FnOnce() -> T) -> T {
+    let level = LEVEL.swap(Level::Error as u8, Ordering::Relaxed);
+    let result = f();
+    LEVEL.store(level, Ordering::Relaxed);
+    result
+}
+
+/// Used by the macros, which check [`enabled`] before formatting anything.
+pub fn write(level: Level, args: fmt::Arguments) {
+    eprintln!("{}: {}", level, args);
+}
+
+#[macro_export]
+macro_rules! log {
+    ($level:expr, $($arg:tt)*) => {
+        if $crate::log::enabled($level) {
+            $crate::log::write($level, format_args!($($arg)*));
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! warn {
+    ($($arg:tt)*) => { $crate::log!($crate::log::Level::Warn, $($arg)*) };
+}
+
+#[macro_export]
+macro_rules! info {
+    ($($arg:tt)*) => { $crate::log!($crate::log::Level::Info, $($arg)*) };
+}
+
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)*) => { $crate::log!($crate::log::Level::Debug, $($arg)*) };
+}
+
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)*) => { $crate::log!($crate::log::Level::Trace, $($arg)*) };
+}
+
+/// Iterations between two looks at the clock, so ticking stays cheap in hot loops.
+const CHECK_STRIDE: u64 = 1 << 20;
+const REPORT_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Reports how far a long loop got at info level, with its rate and, when the total is known, an ETA.
+pub struct Progress {
+    label: String,
+    /// Also an upper bound works, the ETA is then pessimistic.
+    total: Option<u64>,
+    start: Instant,
+    last_report: Instant,
+    next_check: u64,
+}
+
+fn format_eta(eta: Duration) -> String {
+    let seconds = eta.as_secs();
+    match seconds {
+        0..=59 => format!("{}s", seconds),
+        60..=3599 => format!("{}m {:02}s", seconds / 60, seconds % 60),
+        _ => format!("{}h {:02}m", seconds / 3600, seconds % 3600 / 60),
+    }
+}
+
+impl Progress {
+    pub fn new(label: impl Into<String>, total: Option<u64>) -> Self {
+        let now = Instant::now();
+        Progress { label: label.into(), total, start: now, last_report: now, next_check: CHECK_STRIDE }
+    }
+
+    #[inline]
+    pub fn tick(&mut self, done: u64) {
+        if done >= self.next_check {
+            self.check(done);
+        }
+    }
+
+    #[cold]
+    fn check(&mut self, done: u64) {
+        self.next_check = done.saturating_add(CHECK_STRIDE);
+        if !enabled(Level::Info) || self.last_report.elapsed() < REPORT_INTERVAL {
+            return;
+        }
+        self.last_report = Instant::now();
+        write(Level::Info, format_args!("{}", self.message(done, self.start.elapsed())));
+    }
+
+    fn message(&self, done: u64, elapsed: Duration) -> String {
+        let rate = done as f64 / elapsed.as_secs_f64().max(f64::EPSILON);
+        let mut message = format!("{}: {} done, {:.0}/s", self.label, done, rate);
+        if let Some(total) = self.total {
+            let eta = Duration::from_secs_f64(total.saturating_sub(done) as f64 / rate.max(f64::EPSILON));
+            message += &format!(", {:.1}% of {}, ETA {}", done as f64 * 100.0 / total as f64, total, format_eta(eta));
+        }
+        message
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{format_eta, Level, Progress};
+
+    #[test]
+    fn verbosity_test() {
+        assert_eq!(Level::from_verbosity(-1), Level::Error);
+        assert_eq!(Level::from_verbosity(0), Level::Warn);
+        assert_eq!(Level::from_verbosity(2), Level::Debug);
+        assert_eq!(Level::from_verbosity(7), Level::Trace);
+    }
+
+    #[test]
+    fn progress_message_test() {
+        let progress = Progress::new("day 8 part 2", Some(4_000));
+        assert_eq!(progress.message(1_000, Duration::from_secs(2)), "day 8 part 2: 1000 done, 500/s, 25.0% of 4000, ETA 6s");
+        let progress = Progress::new("steps", None);
+        assert_eq!(progress.message(90, Duration::from_millis(500)), "steps: 90 done, 180/s");
+    }
+
+    #[test]
+    fn format_eta_test() {
+        assert_eq!(format_eta(Duration::from_secs(59)), "59s");
+        assert_eq!(format_eta(Duration::from_secs(61)), "1m 01s");
+        assert_eq!(format_eta(Duration::from_secs(3 * 3600 + 5 * 60 + 9)), "3h 05m");
+    }
+}
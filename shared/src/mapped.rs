@@ -0,0 +1,144 @@
+//! Solving streaming days from a memory-mapped file, line-aligned chunks of it on threads of
+//! their own, for inputs of gigabytes.
+
+use std::fs::File;
+use std::path::Path;
+use std::thread;
+
+use memmap2::Mmap;
+
+use crate::answers::InputHasher;
+use crate::parse::ParseError;
+use crate::registry::Part;
+use crate::settings::Settings;
+use crate::stream::{feed_bytes, finish, strip_line_break, StreamError, Streaming};
+
+/// A streaming day whose states of consecutive chunks of lines add up to the state of all of them.
+pub trait Chunked: Streaming<State: Send> {
+    /// Adds the state of the lines right after those `state` has seen.
+    fn merge(state: &mut Self::State, next: Self::State);
+}
+
+/// Splits `bytes` into at most `count` chunks of about the same size, all ending with a line break
+/// but the last one.
+fn chunks(bytes: &[u8], count: usize) -> Vec<&[u8]> {
+    let mut chunks = vec![];
+    let mut start = 0;
+    for index in 1..=count {
+        let middle = (bytes.len() * index / count).max(start);
+        let end = match bytes[middle..].iter().position(|&byte| byte == b'\n') {
+            Some(offset) if index < count => middle + offset + 1,
+            _ => bytes.len(),
+        };
+        if end > start {
+            chunks.push(&bytes[start..end]);
+            start = end;
+        }
+    }
+    chunks
+}
+
+/// Lines of a chunk, counted like [`str::lines`] does.
+fn line_count(chunk: &[u8]) -> usize {
+    chunk.iter().filter(|&&byte| byte == b'\n').count() + usize::from(!chunk.ends_with(b"\n"))
+}
+
+fn solve_chunk<S: Chunked>(chunk: &[u8], first_line: usize, settings: &Settings) -> Result<S::State, ParseError> {
+    let mut state = S::start(settings);
+    for (offset, line) in chunk.split_inclusive(|&byte| byte == b'\n').enumerate() {
+        feed_bytes::<S>(&mut state, first_line + offset, strip_line_break(line))?;
+    }
+    Ok(state)
+}
+
+/// Like [`crate::stream::stream`] on the file at `path`, with a chunk of it per core. The
+/// lines of every chunk are counted first, so errors point at the right line.
+pub fn solve_mapped<S: Chunked>(
+    path: &Path,
+    hasher: &mut InputHasher,
+    settings: &Settings,
+    parts: &[Part],
+) -> Result<(S::Answer1, S::Answer2), StreamError> {
+    let file = File::open(path).map_err(StreamError::Read)?;
+    // SAFETY: the file must not change while mapped, nothing can guard against that, like
+    // for any input read in one go
+    let map = unsafe { Mmap::map(&file) }.map_err(StreamError::Read)?;
+    let threads = thread::available_parallelism().map_or(1, |threads| threads.get());
+    let chunks = chunks(&map, threads);
+    let states = thread::scope(|scope| {
+        scope.spawn(|| hasher.update(&map));
+        let counts: Vec<_> = chunks.iter().map(|chunk| scope.spawn(|| line_count(chunk))).collect();
+        let mut first_line = 1;
+        let mut solving = vec![];
+        for (chunk, count) in chunks.iter().zip(counts) {
+            solving.push(scope.spawn(move || solve_chunk::<S>(chunk, first_line, settings)));
+            first_line += count.join().expect("counting lines panicked");
+        }
+        solving.into_iter().map(|chunk| chunk.join().expect("a chunk panicked")).collect::<Vec<_>>()
+    });
+    let mut state = S::start(settings);
+    for chunk in states {
+        S::merge(&mut state, chunk?);
+    }
+    Ok(finish::<S>(state, parts)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env::temp_dir;
+    use std::fs;
+
+    use crate::answers::{input_hash, InputHasher};
+    use crate::registry::Part;
+    use crate::settings::Settings;
+    use crate::stream::StreamError;
+    use crate::test_day::Sum;
+    use super::{chunks, line_count, solve_mapped};
+
+    fn mapped(name: &str, input: &[u8]) -> Result<((u32, usize), String), StreamError> {
+        let path = temp_dir().join(format!("aoc-mapped-{}-{}", name, std::process::id()));
+        fs::write(&path, input).unwrap();
+        let mut hasher = InputHasher::default();
+        let answers = solve_mapped::<Sum>(&path, &mut hasher, &Settings::default(), &[Part::One, Part::Two]);
+        fs::remove_file(&path).unwrap();
+        Ok((answers?, hasher.finish()))
+    }
+
+    #[test]
+    fn chunks_test() {
+        let input = b"1\n22\n333\n4444\n55555";
+        for count in 1..=30 {
+            let chunks = chunks(input, count);
+            assert!(chunks.len() <= count);
+            assert_eq!(chunks.concat(), input);
+            assert!(chunks[..chunks.len() - 1].iter().all(|chunk| chunk.ends_with(b"\n")));
+            assert_eq!(chunks.iter().map(|chunk| line_count(chunk)).sum::<usize>(), 5);
+        }
+        assert!(chunks(b"", 4).is_empty());
+        assert_eq!(chunks(b"1\n2\n", 1), [b"1\n2\n"]);
+    }
+
+    #[test]
+    fn solve_mapped_test() {
+        let input: String = (1..=1000).map(|number| format!("{}\r\n", number)).collect();
+        assert_eq!(mapped("sum", input.as_bytes()).unwrap(), ((500500, 1000), input_hash(&input)));
+        assert_eq!(mapped("empty", b"").unwrap().0, (0, 0));
+        assert_eq!(mapped("unterminated", b"1\n2").unwrap().0, (3, 2));
+    }
+
+    #[test]
+    fn solve_mapped_error_test() {
+        let input: String = (1..=1000).map(|number| if number == 777 { "x\n".to_string() } else { format!("{}\n", number) }).collect();
+        match mapped("error", input.as_bytes()) {
+            Err(StreamError::Parse(err)) => assert_eq!((err.line, err.snippet.as_str()), (777, "x")),
+            other => panic!("unexpected {:?}", other),
+        }
+        assert!(matches!(mapped("utf8", b"1\n\xff\n"), Err(StreamError::Parse(_))));
+        // the sum overflows in one chunk and then goes on in the next
+        let input: String = (1..=1000).map(|number| if number == 3 { "4294967295\n".to_string() } else { "1\n".to_string() }).collect();
+        match mapped("check", input.as_bytes()) {
+            Err(StreamError::Parse(err)) => assert_eq!((err.line, err.message.as_str()), (1001, "the sum overflows")),
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,182 @@
+//! Counting allocations for `aoc profile`. The binary installs [`Counting`] as its global
+//! allocator, it only counts while [`measure`] runs.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::sync::atomic::{AtomicIsize, AtomicU64, Ordering::Relaxed};
+use std::sync::Mutex;
+
+use crate::bench::Phase;
+use crate::parse::ParseError;
+use crate::registry::{Day, Part};
+use crate::settings::Settings;
+
+thread_local! {
+    /// Only the measuring thread counts, so other threads cannot skew the measurement.
+    static COUNTING: Cell<bool> = const { Cell::new(false) };
+}
+
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+static BYTES: AtomicU64 = AtomicU64::new(0);
+/// Relative to the start of the measurement, freeing older memory makes it negative.
+static LIVE: AtomicIsize = AtomicIsize::new(0);
+static PEAK: AtomicIsize = AtomicIsize::new(0);
+/// One measurement at a time, the counters are global.
+static MEASURING: Mutex<()> = Mutex::new(());
+
+/// The system allocator, counting allocations, their bytes and the live memory when asked to.
+pub struct Counting;
+
+fn counting() -> bool {
+    COUNTING.try_with(Cell::get).unwrap_or(false)
+}
+
+fn allocated(size: usize) {
+    ALLOCATIONS.fetch_add(1, Relaxed);
+    BYTES.fetch_add(size as u64, Relaxed);
+    let live = LIVE.fetch_add(size as isize, Relaxed) + size as isize;
+    PEAK.fetch_max(live, Relaxed);
+}
+
+fn freed(size: usize) {
+    LIVE.fetch_sub(size as isize, Relaxed);
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() && counting() {
+            allocated(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() && counting() {
+            allocated(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        if counting() {
+            freed(layout.size());
+        }
+    }
+
+    /// A reallocation counts as a new allocation replacing the old one.
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() && counting() {
+            freed(layout.size());
+            allocated(new_size);
+        }
+        new_ptr
+    }
+}
+
+/// What a piece of code asked of the allocator.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Usage {
+    pub allocations: u64,
+    /// All bytes allocated, including those freed since.
+    pub bytes: u64,
+    /// Most bytes live at once beyond what was live before.
+    pub peak: u64,
+}
+
+/// Counts the allocations `f` makes on the calling thread. All zeros unless the binary
+/// installed [`Counting`].
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Usage) {
+    let _guard = MEASURING.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+    ALLOCATIONS.store(0, Relaxed);
+    BYTES.store(0, Relaxed);
+    LIVE.store(0, Relaxed);
+    PEAK.store(0, Relaxed);
+    COUNTING.set(true);
+    let result = f();
+    COUNTING.set(false);
+    let usage = Usage {
+        allocations: ALLOCATIONS.load(Relaxed),
+        bytes: BYTES.load(Relaxed),
+        peak: PEAK.load(Relaxed).max(0) as u64,
+    };
+    (result, usage)
+}
+
+/// Measures parsing and both parts of the day separately, each once.
+pub fn profile_day(day: &Day, input: &str, settings: &Settings) -> Result<Vec<(Phase, Usage)>, ParseError> {
+    let (parsed, parse) = measure(|| day.parse_with(input, settings));
+    let parsed = parsed?;
+    let (answer_1, part_1) = measure(|| parsed.part(Part::One));
+    let (answer_2, part_2) = measure(|| parsed.part(Part::Two));
+    answer_1?;
+    answer_2?;
+    Ok(vec![(Phase::Parse, parse), (Phase::Part1, part_1), (Phase::Part2, part_2)])
+}
+
+pub fn format_bytes(bytes: u64) -> String {
+    if bytes < 1 << 10 {
+        format!("{}B", bytes)
+    } else if bytes < 1 << 20 {
+        format!("{:.1}KiB", bytes as f64 / (1u64 << 10) as f64)
+    } else if bytes < 1 << 30 {
+        format!("{:.1}MiB", bytes as f64 / (1u64 << 20) as f64)
+    } else {
+        format!("{:.1}GiB", bytes as f64 / (1u64 << 30) as f64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::hint::black_box;
+
+    use crate::bench::Phase;
+    use crate::settings::Settings;
+    use crate::test_day::SUM;
+    use super::{format_bytes, measure, profile_day, Counting, Usage};
+
+    #[global_allocator]
+    static ALLOCATOR: Counting = Counting;
+
+    #[test]
+    fn measure_test() {
+        let (_, usage) = measure(|| {
+            let first = black_box(vec![0u8; 1000]);
+            drop(first);
+            black_box(vec![0u8; 600]);
+        });
+        assert_eq!(usage, Usage { allocations: 2, bytes: 1600, peak: 1000 });
+
+        let (_, usage) = measure(|| {
+            let mut grown: Vec<u8> = Vec::with_capacity(10);
+            grown.reserve_exact(100);
+            black_box(grown)
+        });
+        assert_eq!(usage, Usage { allocations: 2, bytes: 110, peak: 100 });
+
+        let kept = vec![0u8; 500];
+        let (_, usage) = measure(|| drop(black_box(kept)));
+        assert_eq!(usage, Usage::default());
+    }
+
+    #[test]
+    fn profile_day_test() {
+        let phases = profile_day(&SUM, "1\n2\n3", &Settings::default()).unwrap();
+        assert_eq!(phases.iter().map(|(phase, _)| *phase).collect::<Vec<_>>(), vec![Phase::Parse, Phase::Part1, Phase::Part2]);
+        // the parsed numbers, the answers are short strings
+        assert!(phases[0].1.allocations >= 1);
+        assert!(phases[0].1.bytes >= 12);
+        assert!(profile_day(&SUM, "x", &Settings::default()).is_err());
+    }
+
+    #[test]
+    fn format_bytes_test() {
+        assert_eq!(format_bytes(999), "999B");
+        assert_eq!(format_bytes(1536), "1.5KiB");
+        assert_eq!(format_bytes(3 << 20), "3.0MiB");
+        assert_eq!(format_bytes(5 << 30), "5.0GiB");
+    }
+}
@@ -0,0 +1,141 @@
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+/// Malformed puzzle input, pointing at the offending part of the line.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseError {
+    /// Filled in by the registry, parsers do not need to know their day.
+    pub day: Option<u8>,
+    /// 1-based, one past the last line for a truncated input.
+    pub line: usize,
+    /// 1-based, in chars.
+    pub column: usize,
+    /// The offending part of the line.
+    pub snippet: String,
+    /// The whole offending line, empty for a truncated input.
+    pub source_line: String,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn end_of_input(line: usize, message: impl Into<String>) -> Self {
+        ParseError {
+            day: None,
+            line,
+            column: 1,
+            snippet: String::new(),
+            source_line: String::new(),
+            message: message.into(),
+        }
+    }
+
+    pub fn with_day(self, day: u8) -> Self {
+        ParseError { day: Some(day), ..self }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(day) = self.day {
+            write!(f, "day {}, ", day)?;
+        }
+        writeln!(f, "line {}, column {}: {}", self.line, self.column, self.message)?;
+        let number = self.line.to_string();
+        let gutter = " ".repeat(number.len());
+        writeln!(f, "{} | {}", number, self.source_line)?;
+        write!(
+            f,
+            "{} | {}{}",
+            gutter,
+            " ".repeat(self.column - 1),
+            "^".repeat(self.snippet.chars().count().max(1)),
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+/// One line of the input, knowing where it is so it can point at its own parts in errors.
+#[derive(Debug, Clone, Copy)]
+pub struct Line<'a> {
+    /// 1-based.
+    pub number: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    /// `at` has to be a slice of `self.text`, otherwise the error points at the line start.
+    pub fn error(&self, at: &str, message: impl Into<String>) -> ParseError {
+        let text_start = self.text.as_ptr() as usize;
+        let at_start = at.as_ptr() as usize;
+        let offset = if at_start >= text_start && at_start + at.len() <= text_start + self.text.len() {
+            at_start - text_start
+        } else {
+            0
+        };
+        ParseError {
+            day: None,
+            line: self.number,
+            column: self.text[..offset].chars().count() + 1,
+            snippet: at.to_string(),
+            source_line: self.text.to_string(),
+            message: message.into(),
+        }
+    }
+
+    /// Like [`str::split_once`] on `part` of this line, failing when the delimiter is missing.
+    pub fn split_once(&self, part: &'a str, delimiter: &str) -> Result<(&'a str, &'a str), ParseError> {
+        part.split_once(delimiter).ok_or_else(|| self.error(part, format!("expected {:?}", delimiter)))
+    }
+
+    /// Parses `token` of this line.
+    pub fn number<T: FromStr>(&self, token: &str) -> Result<T, ParseError> {
+        token.parse().map_err(|_| self.error(token, format!("invalid number {:?}", token)))
+    }
+
+    /// Parses all whitespace separated numbers in `part` of this line.
+    pub fn numbers<T: FromStr>(&self, part: &'a str) -> Result<Vec<T>, ParseError> {
+        part.split_whitespace().map(|token| self.number(token)).collect()
+    }
+}
+
+/// Numbered lines of the input.
+pub fn lines(input: &str) -> impl Iterator<Item=Line<'_>> {
+    input.lines().enumerate().map(|(index, text)| Line { number: index + 1, text })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{lines, ParseError};
+
+    #[test]
+    fn error_test() {
+        let line = lines("Game 1: 3 blue\nGame 2: 4 žluté, 1 red").nth(1).unwrap();
+        let (_, cubes) = line.split_once(line.text, ": ").unwrap();
+        let error = line.error(&cubes[2..9], "unknown color").with_day(2);
+        assert_eq!((error.line, error.column), (2, 11));
+        assert_eq!(
+            error.to_string(),
+            "day 2, line 2, column 11: unknown color
+2 | Game 2: 4 žluté, 1 red
+  |           ^^^^^"
+        );
+    }
+
+    #[test]
+    fn number_test() {
+        let line = lines("1 3 x6 10").next().unwrap();
+        assert_eq!(line.numbers::<u8>("1 3"), Ok(vec![1, 3]));
+        let error = line.numbers::<u8>(line.text).unwrap_err();
+        assert_eq!((error.column, error.snippet.as_str()), (5, "x6"));
+    }
+
+    #[test]
+    fn end_of_input_test() {
+        assert_eq!(
+            ParseError::end_of_input(3, "missing distances").to_string(),
+            "line 3, column 1: missing distances\n3 | \n  | ^"
+        );
+    }
+}
@@ -0,0 +1,248 @@
+use std::fmt;
+use std::io::BufRead;
+use std::path::Path;
+use std::str::FromStr;
+
+use crate::answers::InputHasher;
+use crate::generate::Knobs;
+use crate::mapped::{self, Chunked};
+use crate::parse::ParseError;
+use crate::settings::Settings;
+use crate::stream::{self, StreamError, Streaming};
+use crate::Solution;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    One = 1,
+    Two = 2,
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(inp: &str) -> Result<Self, Self::Err> {
+        match inp {
+            "1" => Ok(Self::One),
+            "2" => Ok(Self::Two),
+            _ => Err(format!("part must be 1 or 2, not {:?}", inp)),
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+/// Parsed input of some day with its answer types erased, so days can sit in one registry.
+pub trait Parsed {
+    fn part_1(&self) -> Result<String, ParseError>;
+
+    fn part_2(&self) -> Result<String, ParseError>;
+
+    fn visualize(&self) -> Option<String>;
+
+    fn part(&self, part: Part) -> Result<String, ParseError> {
+        match part {
+            Part::One => self.part_1(),
+            Part::Two => self.part_2(),
+        }
+    }
+}
+
+struct ParsedInput<S: Solution>(S::Input);
+
+impl<S: Solution> Parsed for ParsedInput<S> {
+    fn part_1(&self) -> Result<String, ParseError> {
+        S::check(&self.0, Part::One).map_err(|err| err.with_day(S::DAY))?;
+        Ok(S::part_1(&self.0).to_string())
+    }
+
+    fn part_2(&self) -> Result<String, ParseError> {
+        S::check(&self.0, Part::Two).map_err(|err| err.with_day(S::DAY))?;
+        Ok(S::part_2(&self.0).to_string())
+    }
+
+    fn visualize(&self) -> Option<String> {
+        S::visualize(&self.0)
+    }
+}
+
+type ParseFn = fn(&str, &Settings) -> Result<Box<dyn Parsed>, ParseError>;
+
+fn parse<S: Solution + 'static>(input: &str, settings: &Settings) -> Result<Box<dyn Parsed>, ParseError> {
+    match S::parse_with(input, settings) {
+        Ok(parsed) => Ok(Box::new(ParsedInput::<S>(parsed))),
+        Err(err) => Err(err.with_day(S::DAY)),
+    }
+}
+
+type StreamFn = fn(&mut dyn BufRead, &mut InputHasher, &Settings, &[Part]) -> Result<[String; 2], StreamError>;
+
+fn stream<S: Streaming + 'static>(
+    reader: &mut dyn BufRead,
+    hasher: &mut InputHasher,
+    settings: &Settings,
+    parts: &[Part],
+) -> Result<[String; 2], StreamError> {
+    match stream::stream::<S>(reader, hasher, settings, parts) {
+        Ok((answer_1, answer_2)) => Ok([answer_1.to_string(), answer_2.to_string()]),
+        Err(StreamError::Parse(err)) => Err(StreamError::Parse(err.with_day(S::DAY))),
+        Err(err) => Err(err),
+    }
+}
+
+type MappedFn = fn(&Path, &mut InputHasher, &Settings, &[Part]) -> Result<[String; 2], StreamError>;
+
+fn solve_mapped<S: Chunked + 'static>(
+    path: &Path,
+    hasher: &mut InputHasher,
+    settings: &Settings,
+    parts: &[Part],
+) -> Result<[String; 2], StreamError> {
+    match mapped::solve_mapped::<S>(path, hasher, settings, parts) {
+        Ok((answer_1, answer_2)) => Ok([answer_1.to_string(), answer_2.to_string()]),
+        Err(StreamError::Parse(err)) => Err(StreamError::Parse(err.with_day(S::DAY))),
+        Err(err) => Err(err),
+    }
+}
+
+pub struct Day {
+    pub day: u8,
+    parse: ParseFn,
+    stream: Option<StreamFn>,
+    mapped: Option<MappedFn>,
+}
+
+impl Day {
+    pub const fn new<S: Solution + 'static>() -> Self {
+        Day { day: S::DAY, parse: parse::<S>, stream: None, mapped: None }
+    }
+
+    /// Like [`Day::new`], for a day that can also be solved from a reader.
+    pub const fn streaming<S: Streaming + 'static>() -> Self {
+        Day { day: S::DAY, parse: parse::<S>, stream: Some(stream::<S>), mapped: None }
+    }
+
+    /// Like [`Day::streaming`], for a day that can also solve chunks of a mapped file in parallel.
+    pub const fn chunked<S: Chunked + 'static>() -> Self {
+        Day { day: S::DAY, parse: parse::<S>, stream: Some(stream::<S>), mapped: Some(solve_mapped::<S>) }
+    }
+
+    /// The input parsed under the default settings, those of the puzzle.
+    pub fn parse(&self, input: &str) -> Result<Box<dyn Parsed>, ParseError> {
+        (self.parse)(input, &Settings::default())
+    }
+
+    pub fn parse_with(&self, input: &str, settings: &Settings) -> Result<Box<dyn Parsed>, ParseError> {
+        (self.parse)(input, settings)
+    }
+
+    pub fn streams(&self) -> bool {
+        self.stream.is_some()
+    }
+
+    /// Both answers folded from the lines of `reader`, `None` when the day needs its whole input at once.
+    /// Only `parts` are [checked](Streaming::check_state), see [`stream::stream`].
+    pub fn stream(
+        &self,
+        reader: &mut dyn BufRead,
+        hasher: &mut InputHasher,
+        settings: &Settings,
+        parts: &[Part],
+    ) -> Option<Result<[String; 2], StreamError>> {
+        self.stream.map(|stream| stream(reader, hasher, settings, parts))
+    }
+
+    /// Both answers from the file at `path` mapped into memory, `None` when the day cannot
+    /// solve it in chunks. Only `parts` are checked like for [`Day::stream`].
+    pub fn mapped(&self, path: &Path, hasher: &mut InputHasher, settings: &Settings, parts: &[Part]) -> Option<Result<[String; 2], StreamError>> {
+        self.mapped.map(|mapped| mapped(path, hasher, settings, parts))
+    }
+}
+
+/// One year of puzzles: its solved days, in order, and the generator of synthetic inputs for them.
+pub struct Year {
+    pub year: u16,
+    pub days: &'static [Day],
+    pub generate: fn(u8, &Knobs) -> Result<String, String>,
+    /// Puts a `--set <key>=<value>` into the settings the days run with, tells whether the
+    /// value is not the default one, the answers may then differ from those of the puzzle.
+    pub configure: fn(&mut Settings, &str, &str) -> Result<bool, String>,
+}
+
+impl Year {
+    /// Finds a registered day by its number.
+    pub fn find(&self, day: u8) -> Option<&'static Day> {
+        self.days.iter().find(|registered| registered.day == day)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env::temp_dir;
+    use std::fs;
+
+    use crate::answers::InputHasher;
+    use crate::generate::Knobs;
+    use crate::settings::Settings;
+    use crate::stream::StreamError;
+    use crate::test_day::SUM;
+    use super::{Part, Year};
+
+    const YEAR: Year = Year {
+        year: 2000,
+        days: &[SUM],
+        generate: |_, _: &Knobs| Ok(String::new()),
+        configure: |_, key, _| Err(format!("unknown setting {:?}", key)),
+    };
+
+    #[test]
+    fn find_test() {
+        assert_eq!(YEAR.find(1).map(|day| day.day), Some(1));
+        assert!(YEAR.find(2).is_none());
+    }
+
+    #[test]
+    fn erased_day_test() {
+        let parsed = YEAR.find(1).unwrap().parse("1\n2\n3").unwrap();
+        assert_eq!(parsed.part(Part::One).unwrap(), "6");
+        assert_eq!(parsed.part(Part::Two).unwrap(), "3");
+    }
+
+    #[test]
+    fn erased_error_test() {
+        let error = YEAR.find(1).unwrap().parse("1\nx").err().unwrap();
+        assert_eq!(error.day, Some(1));
+        assert_eq!(error.line, 2);
+    }
+
+    #[test]
+    fn erased_stream_test() {
+        let day = YEAR.find(1).unwrap();
+        assert!(day.streams());
+        let answers = day.stream(&mut &b"1\n2\n3"[..], &mut InputHasher::default(), &Settings::default(), &[Part::One, Part::Two]).unwrap().unwrap();
+        assert_eq!(answers, ["6", "3"]);
+        match day.stream(&mut &b"1\nx"[..], &mut InputHasher::default(), &Settings::default(), &[Part::Two]) {
+            Some(Err(StreamError::Parse(error))) => assert_eq!((error.day, error.line), (Some(1), 2)),
+            _ => panic!("expected a parse error"),
+        }
+    }
+
+    #[test]
+    fn erased_mapped_test() {
+        let path = temp_dir().join(format!("aoc-registry-{}", std::process::id()));
+        fs::write(&path, "1
+x").unwrap();
+        let mapped = YEAR.find(1).unwrap().mapped(&path, &mut InputHasher::default(), &Settings::default(), &[Part::One]);
+        fs::remove_file(&path).unwrap();
+        match mapped {
+            Some(Err(StreamError::Parse(error))) => assert_eq!((error.day, error.line), (Some(1), 2)),
+            _ => panic!("expected a parse error"),
+        }
+    }
+}
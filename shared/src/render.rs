@@ -0,0 +1,180 @@
+//! Colored terminal pictures for `run --visualize` and the tables of the runner.
+
+use std::ops::Range;
+
+use colored::{ColoredString, Colorize};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Style {
+    #[default]
+    Plain,
+    Dim,
+    Good,
+    Warn,
+    Alert,
+    Accent,
+}
+
+impl Style {
+    pub fn paint(self, text: &str) -> ColoredString {
+        match self {
+            Style::Plain => text.normal(),
+            Style::Dim => text.dimmed(),
+            Style::Good => text.green(),
+            Style::Warn => text.yellow(),
+            Style::Alert => text.red().bold(),
+            Style::Accent => text.purple(),
+        }
+    }
+
+    /// `text` padded to `width` characters and then painted, the escape codes would count into
+    /// the width otherwise. Right aligned unless `left`.
+    pub fn paint_padded(self, text: &str, width: usize, left: bool) -> String {
+        let padded = if left { format!("{:<width$}", text) } else { format!("{:>width$}", text) };
+        self.paint(&padded).to_string()
+    }
+}
+
+/// Paints runs of equally styled characters at once, so the escape codes don't repeat per character.
+fn paint_runs(cells: impl Iterator<Item=(char, Style)>) -> String {
+    let mut painted = String::new();
+    let mut run = String::new();
+    let mut run_style = Style::Plain;
+    for (char, style) in cells {
+        if style != run_style && !run.is_empty() {
+            painted += &run_style.paint(&run).to_string();
+            run.clear();
+        }
+        run_style = style;
+        run.push(char);
+    }
+    painted += &run_style.paint(&run).to_string();
+    painted
+}
+
+/// Characters in rows and columns, each with its own style.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Grid {
+    rows: Vec<Vec<(char, Style)>>,
+}
+
+impl Grid {
+    pub fn new(rows: usize, columns: usize, fill: char) -> Self {
+        Grid { rows: vec![vec![(fill, Style::Plain); columns]; rows] }
+    }
+
+    pub fn from_text(text: &str) -> Self {
+        Grid { rows: text.lines().map(|line| line.chars().map(|char| (char, Style::Plain)).collect()).collect() }
+    }
+
+    /// Writes `text` from `column` on, styled, cut at the end of the row.
+    pub fn put(&mut self, row: usize, column: usize, text: &str, style: Style) {
+        if let Some(cells) = self.rows.get_mut(row) {
+            for (cell, char) in cells.iter_mut().skip(column).zip(text.chars()) {
+                *cell = (char, style);
+            }
+        }
+    }
+
+    pub fn style(&mut self, row: usize, columns: Range<usize>, style: Style) {
+        if let Some(cells) = self.rows.get_mut(row) {
+            let end = columns.end.min(cells.len());
+            for cell in cells[columns.start.min(end)..end].iter_mut() {
+                cell.1 = style;
+            }
+        }
+    }
+
+    pub fn render(&self) -> String {
+        self.rows.iter().map(|row| paint_runs(row.iter().copied()) + "\n").collect()
+    }
+}
+
+/// Styles byte `spans` of `text`, later spans win where they overlap.
+pub fn highlight(text: &str, spans: &[(Range<usize>, Style)]) -> String {
+    paint_runs(text.char_indices().map(|(offset, char)| {
+        let style = spans.iter().rev().find(|(span, _)| span.contains(&offset)).map_or(Style::Plain, |(_, style)| *style);
+        (char, style)
+    }))
+}
+
+/// Columns are right aligned unless [`Table::left`] says otherwise, widths fit the widest cell.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Table {
+    header: Vec<String>,
+    left: Vec<bool>,
+    rows: Vec<Vec<(String, Style)>>,
+}
+
+impl Table {
+    pub fn new(header: &[&str]) -> Self {
+        Table { header: header.iter().map(|title| title.to_string()).collect(), left: vec![false; header.len()], rows: vec![] }
+    }
+
+    pub fn left(mut self, column: usize) -> Self {
+        self.left[column] = true;
+        self
+    }
+
+    pub fn row(&mut self, cells: Vec<(String, Style)>) {
+        self.rows.push(cells);
+    }
+
+    pub fn render(&self) -> String {
+        let width = |column: usize| {
+            self.rows.iter().filter_map(|row| row.get(column)).map(|(text, _)| text.chars().count())
+                .chain([self.header[column].chars().count()])
+                .max()
+                .unwrap_or(0)
+        };
+        let widths: Vec<usize> = (0..self.header.len()).map(width).collect();
+        let header = self.header.iter().map(|title| (title.clone(), Style::Plain)).collect();
+        let mut rendered = String::new();
+        for row in std::iter::once(&header).chain(&self.rows) {
+            let cells: Vec<String> = row
+                .iter()
+                .zip(&widths)
+                .zip(&self.left)
+                .map(|(((text, style), &width), &left)| style.paint_padded(text, width, left))
+                .collect();
+            rendered += cells.join("  ").trim_end();
+            rendered.push('\n');
+        }
+        rendered
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{highlight, Grid, Style, Table};
+
+    fn plain() {
+        colored::control::set_override(false);
+    }
+
+    #[test]
+    fn grid_test() {
+        plain();
+        let mut grid = Grid::new(2, 4, '.');
+        grid.put(0, 1, "42", Style::Accent);
+        grid.put(1, 3, "*!", Style::Alert);
+        grid.style(1, 0..10, Style::Dim);
+        assert_eq!(grid.render(), ".42.\n...*\n");
+        assert_eq!(Grid::from_text("ab\nc").render(), "ab\nc\n");
+    }
+
+    #[test]
+    fn highlight_test() {
+        plain();
+        assert_eq!(highlight("two1nine", &[(0..3, Style::Good), (3..4, Style::Alert)]), "two1nine");
+    }
+
+    #[test]
+    fn table_test() {
+        plain();
+        let mut table = Table::new(&["day", "answer", "status"]).left(2);
+        table.row(vec![("1".to_string(), Style::Plain), ("142".to_string(), Style::Plain), ("correct".to_string(), Style::Good)]);
+        table.row(vec![("12".to_string(), Style::Plain), ("7".to_string(), Style::Plain), ("-".to_string(), Style::Dim)]);
+        assert_eq!(table.render(), "day  answer  status\n  1     142  correct\n 12       7  -\n");
+    }
+}
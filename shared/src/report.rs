@@ -0,0 +1,152 @@
+use std::fmt;
+use std::io::{self, Write};
+use std::str::FromStr;
+use std::time::Duration;
+
+use serde::Serialize;
+
+use crate::registry::Part;
+
+/// How `run` prints the answers.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Format {
+    /// Just the answers, one per line.
+    #[default]
+    Text,
+    /// An array of [`Record`]s.
+    Json,
+    /// [`Record`]s under a header line.
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(inp: &str) -> Result<Self, Self::Err> {
+        match inp {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(format!("format must be text, json or csv, not {:?}", inp)),
+        }
+    }
+}
+
+impl fmt::Display for Format {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Format::Text => write!(f, "text"),
+            Format::Json => write!(f, "json"),
+            Format::Csv => write!(f, "csv"),
+        }
+    }
+}
+
+/// One answer as seen by scripts.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Record {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    /// See [`crate::answers::input_hash`].
+    pub input: String,
+    /// Time spent in the part, without parsing.
+    pub duration_ns: u64,
+}
+
+impl Record {
+    pub fn new(year: u16, day: u8, part: Part, answer: String, input: &str, duration: Duration) -> Self {
+        Record { year, day, part: part as u8, answer, input: input.to_string(), duration_ns: duration.as_nanos() as u64 }
+    }
+}
+
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+pub fn write_records(out: &mut impl Write, format: Format, records: &[Record]) -> io::Result<()> {
+    match format {
+        Format::Text => {
+            for record in records {
+                writeln!(out, "{}", record.answer)?;
+            }
+        }
+        Format::Json => {
+            serde_json::to_writer_pretty(&mut *out, records)?;
+            writeln!(out)?;
+        }
+        Format::Csv => {
+            writeln!(out, "year,day,part,answer,input,duration_ns")?;
+            for record in records {
+                writeln!(
+                    out,
+                    "{},{},{},{},{},{}",
+                    record.year,
+                    record.day,
+                    record.part,
+                    csv_field(&record.answer),
+                    record.input,
+                    record.duration_ns,
+                )?;
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::registry::Part;
+    use super::{write_records, Format, Record};
+
+    fn records() -> Vec<Record> {
+        vec![
+            Record::new(2023, 6, Part::One, "288".to_string(), "cbf29ce484222325", Duration::from_micros(3)),
+            Record::new(2023, 6, Part::Two, "a,\"b\"".to_string(), "cbf29ce484222325", Duration::from_nanos(1500)),
+        ]
+    }
+
+    fn written(format: Format) -> String {
+        let mut out = vec![];
+        write_records(&mut out, format, &records()).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn text_test() {
+        assert_eq!(written(Format::Text), "288\na,\"b\"\n");
+    }
+
+    #[test]
+    fn json_test() {
+        let json: serde_json::Value = serde_json::from_str(&written(Format::Json)).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!([
+                { "year": 2023, "day": 6, "part": 1, "answer": "288", "input": "cbf29ce484222325", "duration_ns": 3000 },
+                { "year": 2023, "day": 6, "part": 2, "answer": "a,\"b\"", "input": "cbf29ce484222325", "duration_ns": 1500 },
+            ])
+        );
+    }
+
+    #[test]
+    fn csv_test() {
+        assert_eq!(
+            written(Format::Csv),
+            "year,day,part,answer,input,duration_ns\n2023,6,1,288,cbf29ce484222325,3000\n2023,6,2,\"a,\"\"b\"\"\",cbf29ce484222325,1500\n"
+        );
+    }
+
+    #[test]
+    fn format_test() {
+        assert_eq!("json".parse(), Ok(Format::Json));
+        assert!("yaml".parse::<Format>().is_err());
+    }
+}
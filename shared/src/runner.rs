@@ -0,0 +1,250 @@
+use std::io::BufRead;
+use std::path::Path;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::answers::{input_hash, Answers, InputHasher, Verdict};
+use crate::parse::ParseError;
+use crate::registry::{Day, Part, Parsed};
+use crate::report::Record;
+use crate::settings::Settings;
+use crate::stream::StreamError;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PartRun {
+    pub part: Part,
+    pub answer: String,
+    pub duration: Duration,
+    pub verdict: Verdict,
+}
+
+/// Answers of one day with their timings, checked against the known answers.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DayRun {
+    pub year: u16,
+    pub day: u8,
+    /// See [`input_hash`].
+    pub input: String,
+    /// The whole pass over the input for a streamed day, its parts take no time of their own then.
+    pub parse: Duration,
+    pub parts: Vec<PartRun>,
+}
+
+impl DayRun {
+    /// Parsing and all the parts.
+    pub fn total(&self) -> Duration {
+        self.parse + self.parts.iter().map(|part| part.duration).sum::<Duration>()
+    }
+
+    pub fn part(&self, part: Part) -> Option<&PartRun> {
+        self.parts.iter().find(|run| run.part == part)
+    }
+
+    /// The worst verdict of the parts, a wrong answer beats an unknown one.
+    pub fn verdict(&self) -> Verdict {
+        let verdicts = || self.parts.iter().map(|part| &part.verdict);
+        verdicts()
+            .find(|verdict| matches!(verdict, Verdict::Wrong { .. }))
+            .or_else(|| verdicts().find(|verdict| **verdict == Verdict::Unknown))
+            .cloned()
+            .unwrap_or(Verdict::Correct)
+    }
+
+    pub fn records(&self) -> Vec<Record> {
+        self.parts
+            .iter()
+            .map(|part| Record::new(self.year, self.day, part.part, part.answer.clone(), &self.input, part.duration))
+            .collect()
+    }
+}
+
+/// The parsed input of a day with how long parsing took, so it can be visualized and then run
+/// without parsing it again.
+pub struct ParsedDay<'a> {
+    day: &'a Day,
+    /// See [`input_hash`].
+    input: String,
+    parse: Duration,
+    parsed: Box<dyn Parsed>,
+}
+
+pub fn parse_day<'a>(day: &'a Day, input: &str, settings: &Settings) -> Result<ParsedDay<'a>, ParseError> {
+    let hash = input_hash(input);
+    let start = Instant::now();
+    let parsed = day.parse_with(input, settings)?;
+    Ok(ParsedDay { day, input: hash, parse: start.elapsed(), parsed })
+}
+
+impl ParsedDay<'_> {
+    pub fn visualize(&self) -> Option<String> {
+        self.parsed.visualize()
+    }
+
+    pub fn run(&self, year: u16, parts: &[Part], answers: &Answers) -> Result<DayRun, ParseError> {
+        let parts = parts
+            .iter()
+            .map(|&part| {
+                let start = Instant::now();
+                let answer = self.parsed.part(part)?;
+                let duration = start.elapsed();
+                let verdict = answers.check(year, self.day.day, part, &self.input, &answer);
+                Ok(PartRun { part, answer, duration, verdict })
+            })
+            .collect::<Result<_, ParseError>>()?;
+        Ok(DayRun { year, day: self.day.day, input: self.input.clone(), parse: self.parse, parts })
+    }
+}
+
+pub fn run_day(year: u16, day: &Day, input: &str, settings: &Settings, parts: &[Part], answers: &Answers) -> Result<DayRun, ParseError> {
+    parse_day(day, input, settings)?.run(year, parts, answers)
+}
+
+/// Both answers of a pass over the whole input, timed and checked.
+fn solve_at_once(
+    year: u16,
+    day: &Day,
+    solve: impl FnOnce(&mut InputHasher) -> Option<Result<[String; 2], StreamError>>,
+    parts: &[Part],
+    answers: &Answers,
+) -> Option<Result<DayRun, StreamError>> {
+    let mut hasher = InputHasher::default();
+    let start = Instant::now();
+    let solved = solve(&mut hasher)?;
+    let parse = start.elapsed();
+    let hash = hasher.finish();
+    Some(solved.map(|solved| {
+        let parts = parts
+            .iter()
+            .map(|&part| {
+                let answer = solved[part as usize - 1].clone();
+                let verdict = answers.check(year, day.day, part, &hash, &answer);
+                PartRun { part, answer, duration: Duration::ZERO, verdict }
+            })
+            .collect();
+        DayRun { year, day: day.day, input: hash, parse, parts }
+    }))
+}
+
+/// Like [`run_day`] with the input folded line by line from `reader`, `None` when the day
+/// needs its whole input at once.
+pub fn stream_day(
+    year: u16,
+    day: &Day,
+    reader: &mut dyn BufRead,
+    settings: &Settings,
+    parts: &[Part],
+    answers: &Answers,
+) -> Option<Result<DayRun, StreamError>> {
+    solve_at_once(year, day, |hasher| day.stream(reader, hasher, settings, parts), parts, answers)
+}
+
+/// Like [`run_day`] with the file at `path` mapped into memory and solved in parallel chunks,
+/// `None` when the day cannot do that.
+pub fn map_day(
+    year: u16,
+    day: &Day,
+    path: &Path,
+    settings: &Settings,
+    parts: &[Part],
+    answers: &Answers,
+) -> Option<Result<DayRun, StreamError>> {
+    solve_at_once(year, day, |hasher| day.mapped(path, hasher, settings, parts), parts, answers)
+}
+
+/// Runs every day on its input in a thread of its own, results are in the order of `jobs`.
+pub fn run_parallel(
+    year: u16,
+    jobs: &[(&Day, String)],
+    settings: &Settings,
+    parts: &[Part],
+    answers: &Answers,
+) -> Vec<Result<DayRun, ParseError>> {
+    thread::scope(|scope| {
+        let handles: Vec<_> = jobs
+            .iter()
+            .map(|(day, input)| scope.spawn(move || run_day(year, day, input, settings, parts, answers)))
+            .collect();
+        handles.into_iter().map(|handle| handle.join().expect("a day panicked")).collect()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env::temp_dir;
+    use std::fs;
+
+    use crate::answers::{input_hash, Answers, Verdict};
+    use crate::registry::Part;
+    use crate::settings::Settings;
+    use crate::test_day::SUM;
+    use super::{map_day, parse_day, run_day, run_parallel, stream_day};
+
+    const INPUT: &str = "1\n2\n3\n";
+
+    #[test]
+    fn run_day_test() {
+        let mut answers = Answers::default();
+        answers.record(2023, 1, Part::One, &input_hash(INPUT), "6");
+        answers.record(2023, 1, Part::Two, &input_hash(INPUT), "1");
+
+        let run = run_day(2023, &SUM, INPUT, &Settings::default(), &[Part::One, Part::Two], &answers).unwrap();
+        assert_eq!(run.part(Part::One).unwrap().verdict, Verdict::Correct);
+        assert_eq!(run.part(Part::Two).unwrap().answer, "3");
+        assert_eq!(run.verdict(), Verdict::Wrong { expected: "1".to_string() });
+        assert_eq!(run.records().len(), 2);
+        assert_eq!(run.records()[0].year, 2023);
+
+        let run = run_day(2023, &SUM, INPUT, &Settings::default(), &[Part::One], &answers).unwrap();
+        assert_eq!(run.verdict(), Verdict::Correct);
+        assert!(run.part(Part::Two).is_none());
+
+        let run = run_day(2024, &SUM, INPUT, &Settings::default(), &[Part::One], &answers).unwrap();
+        assert_eq!(run.verdict(), Verdict::Unknown);
+
+        // parsed once, visualized and then run
+        let parsed = parse_day(&SUM, INPUT, &Settings::default()).unwrap();
+        assert!(parsed.visualize().is_none());
+        assert_eq!(parsed.run(2023, &[Part::One], &answers).unwrap().input, input_hash(INPUT));
+
+        let overflowing = "4294967295\n1\n";
+        assert_eq!(run_day(2023, &SUM, overflowing, &Settings::default(), &[Part::Two], &answers).unwrap().part(Part::Two).unwrap().answer, "2");
+        let error = run_day(2023, &SUM, overflowing, &Settings::default(), &[Part::Two, Part::One], &answers).unwrap_err();
+        assert_eq!((error.day, error.line, error.message.as_str()), (Some(1), 3, "the sum overflows"));
+    }
+
+    #[test]
+    fn stream_day_test() {
+        let mut answers = Answers::default();
+        answers.record(2023, 1, Part::Two, &input_hash(INPUT), "3");
+        let run = stream_day(2023, &SUM, &mut INPUT.as_bytes(), &Settings::default(), &[Part::Two], &answers).unwrap().unwrap();
+        let parsed = run_day(2023, &SUM, INPUT, &Settings::default(), &[Part::Two], &answers).unwrap();
+        assert_eq!(run.input, parsed.input);
+        assert_eq!(run.part(Part::Two).unwrap().answer, "3");
+        assert_eq!(run.verdict(), Verdict::Correct);
+        assert!(run.part(Part::One).is_none());
+        assert!(stream_day(2023, &SUM, &mut "1\nx".as_bytes(), &Settings::default(), &[Part::One], &answers).unwrap().is_err());
+    }
+
+    #[test]
+    fn map_day_test() {
+        let path = temp_dir().join(format!("aoc-runner-{}", std::process::id()));
+        fs::write(&path, INPUT).unwrap();
+        let run = map_day(2023, &SUM, &path, &Settings::default(), &[Part::One, Part::Two], &Answers::default()).unwrap().unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(run.input, input_hash(INPUT));
+        assert_eq!(run.parts.iter().map(|part| part.answer.as_str()).collect::<Vec<_>>(), ["6", "3"]);
+    }
+
+    #[test]
+    fn run_parallel_test() {
+        let jobs = [(&SUM, "4\n5".to_string()), (&SUM, INPUT.to_string()), (&SUM, "x".to_string())];
+        let runs = run_parallel(2023, &jobs, &Settings::default(), &[Part::One, Part::Two], &Answers::default());
+        let answers: Vec<_> = runs[..2]
+            .iter()
+            .map(|run| run.as_ref().unwrap().parts.iter().map(|part| part.answer.as_str()).collect::<Vec<_>>())
+            .collect();
+        assert_eq!(answers, [["9", "2"], ["6", "3"]]);
+        assert_eq!(runs[0].as_ref().unwrap().verdict(), Verdict::Unknown);
+        assert!(runs[2].is_err());
+    }
+}
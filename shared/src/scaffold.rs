@@ -0,0 +1,116 @@
+use std::fs::{create_dir_all, read_to_string, write, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+const TEMPLATE: &str = include_str!("aX.rs");
+
+/// The template with its placeholder day replaced.
+pub fn render(day: u8) -> String {
+    TEMPLATE
+        .replace("DayX", &format!("Day{}", day))
+        .replace("const DAY: u8 = 0;", &format!("const DAY: u8 = {};", day))
+}
+
+/// Inserts `line` among the consecutive lines matched by `number_of` so they stay ordered by day.
+fn insert_ordered(lines: &mut Vec<String>, line: String, day: u8, number_of: impl Fn(&str) -> Option<u8>) -> Result<(), String> {
+    let matching: Vec<(usize, u8)> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(index, line)| number_of(line).map(|number| (index, number)))
+        .collect();
+    let Some(&(last, _)) = matching.last() else {
+        return Err(format!("no place for {:?}", line.trim()));
+    };
+    if matching.iter().any(|&(_, number)| number == day) {
+        return Err(format!("day {} is already registered", day));
+    }
+    let index = matching.iter().find(|&&(_, number)| number > day).map_or(last + 1, |&(index, _)| index);
+    lines.insert(index, line);
+    Ok(())
+}
+
+/// Adds the module of the day and its registry entry to the source of lib.rs.
+pub fn register(lib_rs: &str, day: u8) -> Result<String, String> {
+    let mut lines: Vec<String> = lib_rs.lines().map(String::from).collect();
+    insert_ordered(&mut lines, format!("pub mod a{};", day), day, |line| {
+        line.strip_prefix("pub mod a")?.strip_suffix(';')?.parse().ok()
+    })?;
+    insert_ordered(&mut lines, format!("    Day::new::<a{0}::Day{0}>(),", day), day, |line| {
+        // Day::new, Day::streaming or Day::chunked
+        line.strip_prefix("    Day::")?.split_once("::<a")?.1.split_once("::")?.0.parse().ok()
+    })?;
+    Ok(lines.join("\n") + "\n")
+}
+
+const EXAMPLE_ANSWERS: &str = "[example]
+# part_1 = \"\"
+# part_2 = \"\"
+";
+
+/// Writes `src/a<day>.rs` in the crate of a year at `root`, registers it in `src/lib.rs`
+/// and creates an empty example fixture with its answers file, see the build.rs of the crate.
+pub fn scaffold(root: &Path, day: u8) -> io::Result<Vec<PathBuf>> {
+    let source = root.join("src").join(format!("a{}.rs", day));
+    let lib_rs = root.join("src").join("lib.rs");
+    let examples = root.join("examples").join(format!("day{:02}", day));
+    let example = examples.join("example.txt");
+    let example_answers = examples.join("answers.toml");
+
+    let registered = register(&read_to_string(&lib_rs)?, day).map_err(|err| io::Error::new(io::ErrorKind::AlreadyExists, err))?;
+    // create_new so an existing solution is never overwritten
+    OpenOptions::new().write(true).create_new(true).open(&source)?.write_all(render(day).as_bytes())?;
+    write(&lib_rs, registered)?;
+    create_dir_all(&examples)?;
+    if !example.exists() {
+        write(&example, "")?;
+    }
+    if !example_answers.exists() {
+        write(&example_answers, EXAMPLE_ANSWERS)?;
+    }
+    Ok(vec![source, lib_rs, example, example_answers])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{register, render};
+
+    const LIB_RS: &str = "pub mod cli;
+
+pub mod a1;
+pub mod a3;
+
+pub const DAYS: &[Day] = &[
+    Day::new::<a1::Day1>(),
+    Day::streaming::<a3::Day3>(),
+];
+";
+
+    #[test]
+    fn render_test() {
+        let source = render(12);
+        assert!(source.contains("pub struct Day12;"));
+        assert!(source.contains("impl Solution for Day12 {"));
+        assert!(source.contains("const DAY: u8 = 12;"));
+        assert!(!source.contains("DayX"));
+    }
+
+    #[test]
+    fn register_test() {
+        assert_eq!(register(LIB_RS, 2).unwrap(), "pub mod cli;
+
+pub mod a1;
+pub mod a2;
+pub mod a3;
+
+pub const DAYS: &[Day] = &[
+    Day::new::<a1::Day1>(),
+    Day::new::<a2::Day2>(),
+    Day::streaming::<a3::Day3>(),
+];
+");
+        let registered = register(LIB_RS, 10).unwrap();
+        assert!(registered.contains("pub mod a3;\npub mod a10;\n"));
+        assert!(registered.contains("    Day::streaming::<a3::Day3>(),\n    Day::new::<a10::Day10>(),\n];"));
+        assert!(register(LIB_RS, 3).is_err());
+    }
+}
@@ -0,0 +1,38 @@
+//! What `--set` changes about how the days of a year solve, handed to the days explicitly.
+
+use std::any::Any;
+use std::collections::HashMap;
+
+/// Values by key, each of the type the year gives it in [`Year::configure`](crate::Year::configure),
+/// read by the days they concern. Empty, every day solves the puzzle as written.
+#[derive(Debug, Default)]
+pub struct Settings {
+    values: HashMap<&'static str, Box<dyn Any + Send + Sync>>,
+}
+
+impl Settings {
+    /// Replaces the value of `key` set before.
+    pub fn set<T: Any + Send + Sync>(&mut self, key: &'static str, value: T) {
+        self.values.insert(key, Box::new(value));
+    }
+
+    /// `None` when `key` is not set or its value is no `T`.
+    pub fn get<T: Any>(&self, key: &str) -> Option<&T> {
+        self.values.get(key)?.downcast_ref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Settings;
+
+    #[test]
+    fn settings_test() {
+        let mut settings = Settings::default();
+        assert_eq!(settings.get::<u8>("size"), None);
+        settings.set("size", 3u8);
+        settings.set("size", 4u8);
+        assert_eq!(settings.get::<u8>("size"), Some(&4));
+        assert_eq!(settings.get::<u16>("size"), None);
+    }
+}
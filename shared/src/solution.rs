@@ -0,0 +1,36 @@
+use std::fmt::Display;
+
+use crate::parse::ParseError;
+use crate::registry::Part;
+use crate::settings::Settings;
+
+/// One day of the puzzle: parse the input once, then answer both parts from the parsed value.
+pub trait Solution {
+    const DAY: u8;
+
+    type Input;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    /// Like [`Solution::parse`] under the `--set` settings of the run, for a day they change.
+    fn parse_with(input: &str, _settings: &Settings) -> Result<Self::Input, ParseError> {
+        Self::parse(input)
+    }
+
+    fn part_1(input: &Self::Input) -> Self::Answer1;
+
+    fn part_2(input: &Self::Input) -> Self::Answer2;
+
+    /// Why `part` has no answer for an input that parsed, runs before the part is computed so
+    /// an input only one of the parts can answer is still good for the other.
+    fn check(_input: &Self::Input, _part: Part) -> Result<(), ParseError> {
+        Ok(())
+    }
+
+    /// A colored picture of the parsed input for `run --visualize`, see [`crate::render`].
+    fn visualize(_input: &Self::Input) -> Option<String> {
+        None
+    }
+}
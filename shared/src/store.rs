@@ -0,0 +1,46 @@
+//! The TOML files the stores live in, answers, submissions, the bench baseline and the config.
+
+use std::fs::{read_to_string, write};
+use std::io;
+use std::path::Path;
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+/// A missing file is the default, an empty store.
+pub fn load_toml<T: DeserializeOwned + Default>(path: &Path) -> io::Result<T> {
+    match read_to_string(path) {
+        Ok(content) => toml::from_str(&content).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err)),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(T::default()),
+        Err(err) => Err(err),
+    }
+}
+
+pub fn save_toml<T: Serialize>(path: &Path, value: &T) -> io::Result<()> {
+    let content = toml::to_string(value).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+    write(path, content)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env::temp_dir;
+    use std::fs;
+    use std::io;
+
+    use crate::answers::Answers;
+    use crate::registry::Part;
+    use super::{load_toml, save_toml};
+
+    #[test]
+    fn store_test() {
+        let path = temp_dir().join(format!("aoc-store-{}", std::process::id()));
+        assert_eq!(load_toml::<Answers>(&path).unwrap(), Answers::default());
+        let mut answers = Answers::default();
+        answers.record(2023, 1, Part::One, "hash", "142");
+        save_toml(&path, &answers).unwrap();
+        assert_eq!(load_toml::<Answers>(&path).unwrap(), answers);
+        fs::write(&path, "answer = 1").unwrap();
+        assert_eq!(load_toml::<Answers>(&path).unwrap_err().kind(), io::ErrorKind::InvalidData);
+        fs::remove_file(&path).unwrap();
+    }
+}
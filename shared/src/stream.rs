@@ -0,0 +1,160 @@
+//! Solving line-oriented days straight from a reader, so inputs of any size fit in memory.
+
+use std::error::Error;
+use std::fmt;
+use std::io::{self, BufRead};
+use std::str;
+
+use crate::answers::InputHasher;
+use crate::parse::{Line, ParseError};
+use crate::registry::Part;
+use crate::settings::Settings;
+use crate::Solution;
+
+/// A day whose answers can be folded line by line instead of parsing the whole input first.
+pub trait Streaming: Solution {
+    /// What both parts keep between lines, it should not grow with the number of lines. A day
+    /// that needs more, like the rankings of day 7 of 2023, says so on its state and streams only
+    /// to spare the text of the input, not in constant memory.
+    type State: Default;
+
+    /// The state before the first line, like [`Solution::parse_with`] for a day `settings` change.
+    fn start(_settings: &Settings) -> Self::State {
+        Self::State::default()
+    }
+
+    fn feed(state: &mut Self::State, line: Line) -> Result<(), ParseError>;
+
+    /// Like [`Solution::check`], on the state after the last line.
+    fn check_state(_state: &Self::State, _part: Part) -> Result<(), ParseError> {
+        Ok(())
+    }
+
+    fn finish(state: Self::State) -> (Self::Answer1, Self::Answer2);
+}
+
+#[derive(Debug)]
+pub enum StreamError {
+    Read(io::Error),
+    Parse(ParseError),
+}
+
+impl fmt::Display for StreamError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            StreamError::Read(err) => write!(f, "cannot read input: {}", err),
+            StreamError::Parse(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl Error for StreamError {}
+
+impl From<ParseError> for StreamError {
+    fn from(err: ParseError) -> Self {
+        StreamError::Parse(err)
+    }
+}
+
+fn invalid_utf8(number: usize, bytes: &[u8], err: str::Utf8Error) -> ParseError {
+    let valid = str::from_utf8(&bytes[..err.valid_up_to()]).unwrap_or_default();
+    ParseError {
+        day: None,
+        line: number,
+        column: valid.chars().count() + 1,
+        snippet: String::new(),
+        source_line: String::from_utf8_lossy(bytes).into_owned(),
+        message: "invalid UTF-8".to_string(),
+    }
+}
+
+/// `line` without its `\n` or `\r\n`, like [`str::lines`] takes them off.
+pub(crate) fn strip_line_break(line: &[u8]) -> &[u8] {
+    match line.strip_suffix(b"\n") {
+        Some(line) => line.strip_suffix(b"\r").unwrap_or(line),
+        None => line,
+    }
+}
+
+/// Feeds one line of bytes to the day, once they turn out to be UTF-8.
+pub(crate) fn feed_bytes<S: Streaming>(state: &mut S::State, number: usize, bytes: &[u8]) -> Result<(), ParseError> {
+    let text = str::from_utf8(bytes).map_err(|err| invalid_utf8(number, bytes, err))?;
+    S::feed(state, Line { number, text })
+}
+
+/// Both answers once [`Streaming::check_state`] finds nothing wrong with any of `parts`, the answer of
+/// another part may be meaningless.
+pub(crate) fn finish<S: Streaming>(state: S::State, parts: &[Part]) -> Result<(S::Answer1, S::Answer2), ParseError> {
+    for &part in parts {
+        S::check_state(&state, part)?;
+    }
+    Ok(S::finish(state))
+}
+
+/// Feeds the lines of `reader` to the day one at a time, splitting them like [`str::lines`].
+/// Only `parts` are [checked](Streaming::check_state), the answer of another part may be
+/// meaningless. `hasher` sees every byte, so the answers can be checked like those of a parsed input.
+pub fn stream<S: Streaming>(
+    reader: &mut dyn BufRead,
+    hasher: &mut InputHasher,
+    settings: &Settings,
+    parts: &[Part],
+) -> Result<(S::Answer1, S::Answer2), StreamError> {
+    let mut state = S::start(settings);
+    let mut buffer = vec![];
+    let mut number = 0;
+    loop {
+        buffer.clear();
+        if reader.read_until(b'\n', &mut buffer).map_err(StreamError::Read)? == 0 {
+            break;
+        }
+        hasher.update(&buffer);
+        number += 1;
+        feed_bytes::<S>(&mut state, number, strip_line_break(&buffer))?;
+    }
+    Ok(finish::<S>(state, parts)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::answers::{input_hash, InputHasher};
+    use crate::registry::Part;
+    use crate::settings::Settings;
+    use crate::test_day::Sum;
+    use super::{stream, StreamError};
+
+    fn streamed(input: &[u8]) -> Result<((u32, usize), String), StreamError> {
+        let mut hasher = InputHasher::default();
+        let answers = stream::<Sum>(&mut &input[..], &mut hasher, &Settings::default(), &[Part::One, Part::Two])?;
+        Ok((answers, hasher.finish()))
+    }
+
+    #[test]
+    fn stream_test() {
+        assert_eq!(streamed(b"1\n2\r\n3").unwrap(), ((6, 3), input_hash("1\n2\r\n3")));
+        assert_eq!(streamed(b"1\n2\n").unwrap().0, (3, 2));
+        assert_eq!(streamed(b"").unwrap().0, (0, 0));
+    }
+
+    #[test]
+    fn stream_error_test() {
+        match streamed(b"1\n2x\n3") {
+            Err(StreamError::Parse(err)) => assert_eq!((err.line, err.snippet.as_str()), (2, "2x")),
+            other => panic!("unexpected {:?}", other),
+        }
+        match streamed(b"1\n\xc5\xbe2\xff\n") {
+            Err(StreamError::Parse(err)) => assert_eq!((err.line, err.column, err.message.as_str()), (2, 3, "invalid UTF-8")),
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+
+    #[test]
+    fn stream_check_test() {
+        let input = b"4294967295\n1\n";
+        assert_eq!(stream::<Sum>(&mut &input[..], &mut InputHasher::default(), &Settings::default(), &[Part::Two]).unwrap().1, 2);
+        match streamed(input) {
+            Err(StreamError::Parse(err)) => assert_eq!((err.line, err.message.as_str()), (3, "the sum overflows")),
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+}
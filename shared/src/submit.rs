@@ -0,0 +1,328 @@
+use std::env;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+use crate::client::{Client, ClientError};
+use crate::registry::Part;
+use crate::FIRST_YEAR;
+
+/// Environment variable overriding where submitted answers are remembered.
+pub const SUBMISSIONS_FILE_VAR: &str = "AOC_SUBMISSIONS_FILE";
+
+pub const DEFAULT_SUBMISSIONS_FILE: &str = repository_path!("submissions.toml");
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Hint {
+    TooHigh,
+    TooLow,
+}
+
+/// What the server said about a submitted answer.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Outcome {
+    Right,
+    Wrong(Option<Hint>),
+    RateLimited { wait: Duration },
+    /// The part is solved already or not unlocked yet.
+    WrongLevel,
+    Unrecognized(String),
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Outcome::Right => write!(f, "right answer"),
+            Outcome::Wrong(None) => write!(f, "wrong answer"),
+            Outcome::Wrong(Some(Hint::TooHigh)) => write!(f, "wrong answer, too high"),
+            Outcome::Wrong(Some(Hint::TooLow)) => write!(f, "wrong answer, too low"),
+            Outcome::RateLimited { wait } => write!(f, "answered too recently, wait {}s", wait.as_secs()),
+            Outcome::WrongLevel => write!(f, "the part is already solved or still locked"),
+            Outcome::Unrecognized(text) => write!(f, "unrecognized response: {}", text),
+        }
+    }
+}
+
+/// Text of the `<article>` with the message, without tags.
+fn article_text(html: &str) -> String {
+    let article = html
+        .split_once("<article")
+        .and_then(|(_, rest)| rest.split_once('>'))
+        .and_then(|(_, rest)| rest.split_once("</article>"))
+        .map_or(html, |(article, _)| article);
+    let mut text = String::new();
+    let mut in_tag = false;
+    for char in article.chars() {
+        match char {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => text.push(char),
+            _ => {}
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// "You have 1m 23s left to wait." or "You have 42s left to wait."
+fn parse_wait(text: &str) -> Option<Duration> {
+    let (before, _) = text.split_once(" left to wait")?;
+    let (_, amount) = before.rsplit_once("have ")?;
+    let mut seconds = 0;
+    for token in amount.split_whitespace() {
+        seconds += match token.split_at(token.len().checked_sub(1)?) {
+            (minutes, "m") => minutes.parse::<u64>().ok()? * 60,
+            (secs, "s") => secs.parse::<u64>().ok()?,
+            _ => return None,
+        };
+    }
+    Some(Duration::from_secs(seconds))
+}
+
+pub fn parse_response(html: &str) -> Outcome {
+    let text = article_text(html);
+    if text.contains("That's the right answer") {
+        Outcome::Right
+    } else if text.contains("That's not the right answer") {
+        Outcome::Wrong(if text.contains("your answer is too high") {
+            Some(Hint::TooHigh)
+        } else if text.contains("your answer is too low") {
+            Some(Hint::TooLow)
+        } else {
+            None
+        })
+    } else if text.contains("You gave an answer too recently") {
+        Outcome::RateLimited { wait: parse_wait(&text).unwrap_or(Duration::from_secs(60)) }
+    } else if text.contains("You don't seem to be solving the right level") {
+        Outcome::WrongLevel
+    } else {
+        Outcome::Unrecognized(text)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum Verdict {
+    Right,
+    Wrong,
+    TooHigh,
+    TooLow,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct Submission {
+    #[serde(default = "first_year")]
+    year: u16,
+    day: u8,
+    part: u8,
+    answer: String,
+    verdict: Verdict,
+}
+
+fn first_year() -> u16 {
+    FIRST_YEAR
+}
+
+/// Why an answer is not worth sending.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Refusal {
+    AlreadyRight(String),
+    AlreadyWrong,
+    NotBelow(String),
+    NotAbove(String),
+    Cooldown(Duration),
+}
+
+impl fmt::Display for Refusal {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Refusal::AlreadyRight(answer) => write!(f, "already solved with {}", answer),
+            Refusal::AlreadyWrong => write!(f, "this answer was already submitted and it was wrong"),
+            Refusal::NotBelow(answer) => write!(f, "{} was already too high", answer),
+            Refusal::NotAbove(answer) => write!(f, "{} was already too low", answer),
+            Refusal::Cooldown(wait) => write!(f, "still cooling down, wait {}s", wait.as_secs()),
+        }
+    }
+}
+
+/// Answers sent so far with the server's verdict, and until when the server asked to wait.
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Submissions {
+    /// Unix time in seconds.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    cooldown_until: Option<u64>,
+    #[serde(default, rename = "submission")]
+    submissions: Vec<Submission>,
+}
+
+fn unix_now() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_secs()
+}
+
+impl Submissions {
+    /// Refuses answers known to be wrong, including numbers beyond a too high or too low one.
+    pub fn check(&self, year: u16, day: u8, part: Part, answer: &str, now: u64) -> Result<(), Refusal> {
+        if let Some(wait) = self.cooldown_until.and_then(|until| until.checked_sub(now)).filter(|wait| *wait > 0) {
+            return Err(Refusal::Cooldown(Duration::from_secs(wait)));
+        }
+        let number = answer.parse::<i128>().ok();
+        for submission in self.submissions.iter().filter(|submission| submission.year == year && submission.day == day && submission.part == part as u8) {
+            let previous = submission.answer.parse::<i128>().ok();
+            match submission.verdict {
+                Verdict::Right => return Err(Refusal::AlreadyRight(submission.answer.clone())),
+                _ if submission.answer == answer => return Err(Refusal::AlreadyWrong),
+                Verdict::TooHigh if number.is_some() && number >= previous => {
+                    return Err(Refusal::NotBelow(submission.answer.clone()));
+                }
+                Verdict::TooLow if number.is_some() && number <= previous => {
+                    return Err(Refusal::NotAbove(submission.answer.clone()));
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    pub fn record(&mut self, year: u16, day: u8, part: Part, answer: &str, outcome: &Outcome, now: u64) {
+        let verdict = match outcome {
+            Outcome::Right => Verdict::Right,
+            Outcome::Wrong(None) => Verdict::Wrong,
+            Outcome::Wrong(Some(Hint::TooHigh)) => Verdict::TooHigh,
+            Outcome::Wrong(Some(Hint::TooLow)) => Verdict::TooLow,
+            Outcome::RateLimited { wait } => {
+                self.cooldown_until = Some(now + wait.as_secs());
+                return;
+            }
+            Outcome::WrongLevel | Outcome::Unrecognized(_) => return,
+        };
+        self.submissions.push(Submission { year, day, part: part as u8, answer: answer.to_string(), verdict });
+    }
+}
+
+#[derive(Debug)]
+pub enum SubmitError {
+    Refused(Refusal),
+    Client(ClientError),
+}
+
+impl fmt::Display for SubmitError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SubmitError::Refused(refusal) => write!(f, "not submitted, {}", refusal),
+            SubmitError::Client(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for SubmitError {}
+
+/// Posts the answer unless the submissions show it is pointless, and remembers the verdict.
+pub fn submit(
+    client: &Client,
+    submissions: &mut Submissions,
+    year: u16,
+    day: u8,
+    part: Part,
+    answer: &str,
+) -> Result<Outcome, SubmitError> {
+    submissions.check(year, day, part, answer, unix_now()).map_err(SubmitError::Refused)?;
+    let level = (part as u8).to_string();
+    let html = client
+        .post_form(year, day, "/answer", &[("level", &level), ("answer", answer)])
+        .map_err(SubmitError::Client)?;
+    let outcome = parse_response(&html);
+    submissions.record(year, day, part, answer, &outcome, unix_now());
+    Ok(outcome)
+}
+
+/// An explicit path wins over [`SUBMISSIONS_FILE_VAR`] and the default file in the repository.
+pub fn resolve(explicit: Option<&Path>) -> PathBuf {
+    match explicit {
+        Some(path) => path.to_path_buf(),
+        None => env::var_os(SUBMISSIONS_FILE_VAR).map(PathBuf::from).unwrap_or_else(|| PathBuf::from(DEFAULT_SUBMISSIONS_FILE)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env::temp_dir;
+    use std::time::Duration;
+
+    use crate::client::Client;
+    use crate::config::Config;
+    use crate::registry::Part;
+    use crate::test_server::serve;
+    use super::{parse_response, submit, Hint, Outcome, Refusal, SubmitError, Submissions};
+
+    const RIGHT: &str = "<main>\n<article><p>That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer to restoring snow operations. <a href=\"/2023/day/6#part2\">[Continue to Part Two]</a></p></article>\n</main>";
+    const TOO_HIGH: &str = "<main>\n<article><p>That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data. Please wait one minute before trying again. <a href=\"/2023/day/6\">[Return to Day 6]</a></p></article>\n</main>";
+    const RATE_LIMITED: &str = "<main>\n<article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 23s left to wait. <a href=\"/2023/day/6\">[Return to Day 6]</a></p></article>\n</main>";
+    const WRONG_LEVEL: &str = "<main>\n<article><p>You don't seem to be solving the right level.  Did you already complete it? <a href=\"/2023/day/6\">[Return to Day 6]</a></p></article>\n</main>";
+
+    #[test]
+    fn parse_response_test() {
+        assert_eq!(parse_response(RIGHT), Outcome::Right);
+        assert_eq!(parse_response(TOO_HIGH), Outcome::Wrong(Some(Hint::TooHigh)));
+        assert_eq!(parse_response(&TOO_HIGH.replace("too high", "too low")), Outcome::Wrong(Some(Hint::TooLow)));
+        assert_eq!(parse_response(&TOO_HIGH.replace("; your answer is too high", "")), Outcome::Wrong(None));
+        assert_eq!(parse_response(RATE_LIMITED), Outcome::RateLimited { wait: Duration::from_secs(83) });
+        assert_eq!(
+            parse_response(&RATE_LIMITED.replace("1m 23s", "42s")),
+            Outcome::RateLimited { wait: Duration::from_secs(42) }
+        );
+        assert_eq!(parse_response(WRONG_LEVEL), Outcome::WrongLevel);
+        assert_eq!(parse_response("<article><p>Something <em>else</em></p></article>"), Outcome::Unrecognized("Something else".to_string()));
+    }
+
+    #[test]
+    fn check_test() {
+        let mut submissions = Submissions::default();
+        assert_eq!(submissions.check(2023, 6, Part::One, "300", 0), Ok(()));
+        submissions.record(2023, 6, Part::One, "300", &Outcome::Wrong(Some(Hint::TooHigh)), 0);
+        submissions.record(2023, 6, Part::One, "200", &Outcome::Wrong(Some(Hint::TooLow)), 0);
+        submissions.record(2023, 6, Part::One, "abc", &Outcome::Wrong(None), 0);
+        assert_eq!(submissions.check(2023, 6, Part::One, "300", 0), Err(Refusal::AlreadyWrong));
+        assert_eq!(submissions.check(2023, 6, Part::One, "abc", 0), Err(Refusal::AlreadyWrong));
+        assert_eq!(submissions.check(2023, 6, Part::One, "301", 0), Err(Refusal::NotBelow("300".to_string())));
+        assert_eq!(submissions.check(2023, 6, Part::One, "150", 0), Err(Refusal::NotAbove("200".to_string())));
+        assert_eq!(submissions.check(2023, 6, Part::One, "288", 0), Ok(()));
+        assert_eq!(submissions.check(2023, 6, Part::Two, "300", 0), Ok(()));
+
+        submissions.record(2023, 6, Part::One, "288", &Outcome::Right, 0);
+        assert_eq!(submissions.check(2023, 6, Part::One, "289", 0), Err(Refusal::AlreadyRight("288".to_string())));
+
+        submissions.record(2023, 6, Part::Two, "71503", &Outcome::RateLimited { wait: Duration::from_secs(60) }, 1000);
+        assert_eq!(submissions.check(2023, 6, Part::Two, "71503", 1030), Err(Refusal::Cooldown(Duration::from_secs(30))));
+        assert_eq!(submissions.check(2023, 6, Part::Two, "71503", 1060), Ok(()));
+
+        assert_eq!(submissions.check(2024, 6, Part::One, "300", 2000), Ok(()));
+
+        let saved = toml::to_string(&submissions).unwrap();
+        assert_eq!(toml::from_str::<Submissions>(&saved).unwrap(), submissions);
+        let old = "[[submission]]\nday = 6\npart = 1\nanswer = \"288\"\nverdict = \"right\"\n";
+        assert_eq!(toml::from_str::<Submissions>(old).unwrap().check(2023, 6, Part::One, "1", 0), Err(Refusal::AlreadyRight("288".to_string())));
+    }
+
+    #[test]
+    fn submit_test() {
+        let (base_url, requests) = serve(vec![(200, TOO_HIGH), (200, RIGHT)]);
+        let config = Config { session: Some("cafe".to_string()), base_url: Some(base_url), throttle_seconds: Some(0) };
+        let client = Client::new(&config, temp_dir().join(format!("aoc-submit-{}", std::process::id())));
+        let mut submissions = Submissions::default();
+
+        assert_eq!(submit(&client, &mut submissions, 2023, 6, Part::Two, "80000").unwrap(), Outcome::Wrong(Some(Hint::TooHigh)));
+        assert!(matches!(
+            submit(&client, &mut submissions, 2023, 6, Part::Two, "80000"),
+            Err(SubmitError::Refused(Refusal::AlreadyWrong))
+        ));
+        assert_eq!(submit(&client, &mut submissions, 2023, 6, Part::Two, "71503").unwrap(), Outcome::Right);
+
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests.len(), 2);
+        assert!(requests[0].starts_with("POST /2023/day/6/answer HTTP/1.1\r\n"));
+        assert!(requests[0].ends_with("\r\n\r\nlevel=2&answer=80000"));
+        assert!(requests[1].ends_with("\r\n\r\nlevel=2&answer=71503"));
+    }
+}
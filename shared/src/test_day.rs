@@ -0,0 +1,70 @@
+//! A tiny day for testing the registry and the runner without any year.
+
+use crate::mapped::Chunked;
+use crate::parse::{lines, Line, ParseError};
+use crate::registry::{Day, Part};
+use crate::stream::Streaming;
+use crate::Solution;
+
+/// Part 1 sums the numbers on the lines, part 2 counts them.
+pub struct Sum;
+
+impl Solution for Sum {
+    const DAY: u8 = 1;
+
+    type Input = Vec<u32>;
+    type Answer1 = u32;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        lines(input).map(|line| line.number(line.text)).collect()
+    }
+
+    fn part_1(numbers: &Self::Input) -> u32 {
+        numbers.iter().sum()
+    }
+
+    fn part_2(numbers: &Self::Input) -> usize {
+        numbers.len()
+    }
+
+    fn check(numbers: &Self::Input, part: Part) -> Result<(), ParseError> {
+        match part {
+            Part::One if numbers.iter().try_fold(0u32, |sum, &number| sum.checked_add(number)).is_none() => {
+                Err(ParseError::end_of_input(numbers.len() + 1, "the sum overflows"))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+impl Streaming for Sum {
+    /// The wrapped sum, the count and whether the sum overflowed.
+    type State = (u32, usize, bool);
+
+    fn feed(state: &mut Self::State, line: Line) -> Result<(), ParseError> {
+        let (sum, overflowed) = state.0.overflowing_add(line.number::<u32>(line.text)?);
+        *state = (sum, state.1 + 1, state.2 || overflowed);
+        Ok(())
+    }
+
+    fn check_state(state: &Self::State, part: Part) -> Result<(), ParseError> {
+        match part {
+            Part::One if state.2 => Err(ParseError::end_of_input(state.1 + 1, "the sum overflows")),
+            _ => Ok(()),
+        }
+    }
+
+    fn finish(state: Self::State) -> (u32, usize) {
+        (state.0, state.1)
+    }
+}
+
+impl Chunked for Sum {
+    fn merge(state: &mut Self::State, next: Self::State) {
+        let (sum, overflowed) = state.0.overflowing_add(next.0);
+        *state = (sum, state.1 + next.1, state.2 || next.2 || overflowed);
+    }
+}
+
+pub const SUM: Day = Day::chunked::<Sum>();
@@ -0,0 +1,390 @@
+use std::env;
+use std::io::{self as stdio, Write};
+use std::path::Path;
+use std::process::exit;
+
+use colored::{ColoredString, Colorize};
+
+use aoc_shared::answers::{self, input_hash, Answers, Verdict};
+use aoc_shared::bench::{self, bench_day, format_duration, Baseline};
+use aoc_shared::cli::{parse_args, BenchArgs, Command, ProfileArgs, RunArgs, SubmitArgs, USAGE};
+use aoc_shared::client::Client;
+use aoc_shared::config::Config;
+use aoc_shared::generate::Knobs;
+use aoc_shared::input::{self, InputSource};
+use aoc_shared::log;
+use aoc_shared::memory::{format_bytes, profile_day, Counting};
+use aoc_shared::registry::Part;
+use aoc_shared::render::{Style, Table};
+use aoc_shared::report::{write_records, Format};
+use aoc_shared::runner::{map_day, parse_day, run_parallel, stream_day, DayRun, PartRun};
+use aoc_shared::scaffold;
+use aoc_shared::settings::Settings;
+use aoc_shared::store::{load_toml, save_toml};
+use aoc_shared::submit::{self, Outcome, Submissions};
+use aoc_shared::{warn, Day, Year};
+
+// counts nothing unless `aoc profile` measures
+#[global_allocator]
+static ALLOCATOR: Counting = Counting;
+
+/// Every year with a crate, in order. A new year is a copy of the crate of the last one
+/// without its days, added to the workspace, the dependencies and here.
+const YEARS: &[Year] = &[advent_of_code_2023::YEAR];
+
+/// The requested year, the latest one by default.
+fn year(requested: Option<u16>) -> Result<&'static Year, String> {
+    match requested {
+        Some(requested) => YEARS.iter().find(|year| year.year == requested).ok_or_else(|| format!("no solutions for {}", requested)),
+        None => YEARS.last().ok_or_else(|| "no years".to_string()),
+    }
+}
+
+fn find(year: &Year, day: u8) -> Result<&'static Day, String> {
+    year.find(day).ok_or_else(|| format!("day {} of {} is not solved yet", day, year.year))
+}
+
+fn verdict_style(verdict: &Verdict) -> Style {
+    match verdict {
+        Verdict::Correct => Style::Good,
+        Verdict::Wrong { .. } => Style::Alert,
+        Verdict::Unknown => Style::Warn,
+    }
+}
+
+fn verdict_status(verdict: &Verdict) -> ColoredString {
+    verdict_style(verdict).paint(&verdict.to_string())
+}
+
+fn write_results(format: Format, runs: &[DayRun]) -> Result<(), String> {
+    let records: Vec<_> = runs.iter().flat_map(DayRun::records).collect();
+    let mut stdout = stdio::stdout().lock();
+    write_records(&mut stdout, format, &records)
+        .and_then(|_| stdout.flush())
+        .map_err(|err| format!("cannot write results: {}", err))
+}
+
+fn print_summary(rows: &[(u8, Result<DayRun, String>)]) {
+    let mut table = Table::new(&["day", "part 1", "part 2", "time", "status"]).left(4);
+    for (day, row) in rows {
+        let plain = |text: String| (text, Style::Plain);
+        match row {
+            Ok(run) => {
+                let answer = |part| run.part(part).map_or("-", |run: &PartRun| run.answer.as_str()).to_string();
+                let verdict = run.verdict();
+                table.row(vec![
+                    plain(day.to_string()),
+                    plain(answer(Part::One)),
+                    plain(answer(Part::Two)),
+                    plain(format_duration(run.total())),
+                    (verdict.to_string(), verdict_style(&verdict)),
+                ]);
+            }
+            Err(err) => table.row(vec![
+                plain(day.to_string()),
+                plain("-".to_string()),
+                plain("-".to_string()),
+                plain("-".to_string()),
+                (err.clone(), Style::Alert),
+            ]),
+        }
+    }
+    print!("{}", table.render());
+}
+
+/// `custom` settings change the answers, the known ones are then neither checked nor recorded.
+fn run(year: &Year, args: RunArgs, settings: &Settings, custom: bool) -> Result<(), String> {
+    let answers_path = answers::resolve(args.answers.as_deref());
+    let mut answers = if custom {
+        warn!("answers are neither checked nor recorded with --set away from the defaults");
+        Answers::default()
+    } else {
+        load_toml::<Answers>(&answers_path).map_err(|err| format!("cannot load {}: {}", answers_path.display(), err))?
+    };
+    let parts = match args.part {
+        Some(part) => vec![part],
+        None => vec![Part::One, Part::Two],
+    };
+
+    let mut failures = 0;
+    let runs = match args.day {
+        Some(day) => {
+            let day = find(year, day)?;
+            let source = input::resolve(args.input.as_deref(), year.year, day.day);
+            let run = if args.stream {
+                let mut reader = source.open().map_err(|err| format!("cannot read {}: {}", source, err))?;
+                stream_day(year.year, day, &mut reader, settings, &parts, &answers)
+                    .ok_or_else(|| format!("day {} needs its whole input, it cannot be streamed", day.day))?
+                    .map_err(|err| err.to_string())?
+            } else if args.mmap {
+                let InputSource::Path(path) = &source else {
+                    return Err("--mmap needs an input file, not stdin".to_string());
+                };
+                map_day(year.year, day, path, settings, &parts, &answers)
+                    .ok_or_else(|| format!("day {} cannot be solved in chunks", day.day))?
+                    .map_err(|err| err.to_string())?
+            } else {
+                let input = source.read().map_err(|err| format!("cannot read {}: {}", source, err))?;
+                let parsed = parse_day(day, &input, settings).map_err(|err| err.to_string())?;
+                if args.visualize {
+                    match parsed.visualize() {
+                        Some(picture) => eprint!("{}", picture),
+                        None => warn!("day {} has nothing to visualize", day.day),
+                    }
+                }
+                parsed.run(year.year, &parts, &answers).map_err(|err| err.to_string())?
+            };
+            for part in &run.parts {
+                eprintln!("day {} part {}: {}", run.day, part.part, verdict_status(&part.verdict));
+            }
+            write_results(args.format, std::slice::from_ref(&run))?;
+            vec![run]
+        }
+        None => {
+            let mut rows: Vec<(u8, Result<DayRun, String>)> = vec![];
+            let mut jobs = vec![];
+            for day in year.days {
+                let source = input::resolve(None, year.year, day.day);
+                match source.read() {
+                    Ok(input) => jobs.push((day, input)),
+                    Err(_) => rows.push((day.day, Err("no input".to_string()))),
+                }
+            }
+            for ((day, _), result) in jobs.iter().zip(run_parallel(year.year, &jobs, settings, &parts, &answers)) {
+                rows.push((day.day, result.map_err(|err| {
+                    failures += 1;
+                    eprintln!("{}", err);
+                    "parse error".to_string()
+                })));
+            }
+            rows.sort_by_key(|(day, _)| *day);
+            match args.format {
+                Format::Text => print_summary(&rows),
+                format => {
+                    let runs: Vec<_> = rows.iter().filter_map(|(_, row)| row.as_ref().ok()).cloned().collect();
+                    write_results(format, &runs)?;
+                }
+            }
+            rows.into_iter().filter_map(|(_, row)| row.ok()).collect()
+        }
+    };
+
+    let mut regressions = 0;
+    let mut recorded = 0;
+    for run in &runs {
+        for part in &run.parts {
+            match part.verdict {
+                Verdict::Wrong { .. } => regressions += 1,
+                Verdict::Unknown if args.record && !custom && answers.record(run.year, run.day, part.part, &run.input, &part.answer) => {
+                    eprintln!("day {} part {}: {}", run.day, part.part, "recorded".yellow());
+                    recorded += 1;
+                }
+                _ => {}
+            }
+        }
+    }
+    if recorded > 0 {
+        save_toml(&answers_path, &answers).map_err(|err| format!("cannot save {}: {}", answers_path.display(), err))?;
+    }
+    if regressions > 0 {
+        return Err(format!("{} answer(s) differ from {}", regressions, answers_path.display()));
+    }
+    if failures > 0 {
+        return Err(format!("{} day(s) failed to parse", failures));
+    }
+    Ok(())
+}
+
+fn bench(year: &Year, args: BenchArgs, settings: &Settings) -> Result<(), String> {
+    let days: Vec<&Day> = if args.days.is_empty() {
+        year.days.iter().collect()
+    } else {
+        args.days.iter().map(|&day| find(year, day)).collect::<Result<_, _>>()?
+    };
+    let baseline_path = args.baseline.unwrap_or_else(|| bench::DEFAULT_BASELINE_FILE.into());
+    let mut baseline = load_toml::<Baseline>(&baseline_path).map_err(|err| format!("cannot load {}: {}", baseline_path.display(), err))?;
+
+    let mut slowdowns = 0;
+    println!("{:>3}  {:<6}  {:>9}  {:>9}  {:>9}  {:>9}  baseline", "day", "phase", "median", "p90", "p99", "min");
+    for day in days {
+        let source = input::resolve(args.input.as_deref(), year.year, day.day);
+        let input = match source.read() {
+            Ok(input) => input,
+            Err(_) if args.days.is_empty() && source != InputSource::Stdin => {
+                eprintln!("day {}: skipped, no input at {}", day.day, source);
+                continue;
+            }
+            Err(err) => return Err(format!("cannot read {}: {}", source, err)),
+        };
+        let phases = bench_day(day, &input, settings, args.options).map_err(|err| err.to_string())?;
+        for (phase, stats) in phases {
+            let change = match baseline.change(year.year, day.day, phase, stats.median) {
+                Some(change) if change > args.threshold => {
+                    slowdowns += 1;
+                    format!("{:+.1}% slower", change).red().bold()
+                }
+                Some(change) => format!("{:+.1}%", change).normal(),
+                None => "-".normal(),
+            };
+            println!(
+                "{:>3}  {:<6}  {:>9}  {:>9}  {:>9}  {:>9}  {}",
+                day.day,
+                phase,
+                format_duration(stats.median),
+                format_duration(stats.p90),
+                format_duration(stats.p99),
+                format_duration(stats.min),
+                change,
+            );
+            if args.save {
+                baseline.set(year.year, day.day, phase, stats.median);
+            }
+        }
+    }
+
+    if args.save {
+        save_toml(&baseline_path, &baseline).map_err(|err| format!("cannot save {}: {}", baseline_path.display(), err))?;
+    }
+    if slowdowns > 0 {
+        eprintln!("{} phase(s) are more than {}% slower than {}", slowdowns, args.threshold, baseline_path.display());
+    }
+    Ok(())
+}
+
+fn profile(year: &Year, args: ProfileArgs, settings: &Settings) -> Result<(), String> {
+    let days: Vec<&Day> = if args.days.is_empty() {
+        year.days.iter().collect()
+    } else {
+        args.days.iter().map(|&day| find(year, day)).collect::<Result<_, _>>()?
+    };
+    let mut table = Table::new(&["day", "phase", "allocations", "bytes", "peak"]).left(1);
+    for day in days {
+        let source = input::resolve(args.input.as_deref(), year.year, day.day);
+        let input = match source.read() {
+            Ok(input) => input,
+            Err(_) if args.days.is_empty() && source != InputSource::Stdin => {
+                eprintln!("day {}: skipped, no input at {}", day.day, source);
+                continue;
+            }
+            Err(err) => return Err(format!("cannot read {}: {}", source, err)),
+        };
+        for (phase, usage) in profile_day(day, &input, settings).map_err(|err| err.to_string())? {
+            table.row(vec![
+                (day.day.to_string(), Style::Plain),
+                (phase.to_string(), Style::Plain),
+                (usage.allocations.to_string(), Style::Plain),
+                (format_bytes(usage.bytes), Style::Plain),
+                (format_bytes(usage.peak), Style::Plain),
+            ]);
+        }
+    }
+    print!("{}", table.render());
+    Ok(())
+}
+
+fn new(year: &Year, day: u8) -> Result<(), String> {
+    // the crate of the year in the repository the runner was built from
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join(year.year.to_string());
+    let written = scaffold::scaffold(&root, day).map_err(|err| format!("cannot create day {}: {}", day, err))?;
+    for path in written {
+        eprintln!("wrote {}", path.display());
+    }
+    Ok(())
+}
+
+fn client() -> Result<Client, String> {
+    let config = Config::from_env().map_err(|err| format!("cannot load config: {}", err))?;
+    Ok(Client::new(&config, input::input_dir().join(".last_request")))
+}
+
+fn fetch(year: &Year, days: Vec<u8>) -> Result<(), String> {
+    let client = client()?;
+    for day in days {
+        let cache = input::day_path(&input::input_dir(), year.year, day);
+        match client.fetch_input(year.year, day, &cache) {
+            Ok(true) => eprintln!("day {}: downloaded to {}", day, cache.display()),
+            Ok(false) => eprintln!("day {}: already in {}", day, cache.display()),
+            Err(err) => return Err(format!("day {}: {}", day, err)),
+        }
+    }
+    Ok(())
+}
+
+fn submit(year: &Year, args: SubmitArgs) -> Result<(), String> {
+    // the input hash is known only for computed answers, which can then be recorded when right
+    let (answer, hash) = match args.answer {
+        Some(answer) => (answer, None),
+        None => {
+            let day = find(year, args.day)?;
+            let source = input::resolve(args.input.as_deref(), year.year, day.day);
+            let input = source.read().map_err(|err| format!("cannot read {}: {}", source, err))?;
+            let parsed = day.parse(&input).map_err(|err| err.to_string())?;
+            let answer = parsed.part(args.part).map_err(|err| err.to_string())?;
+            (answer, Some(input_hash(&input)))
+        }
+    };
+    let submissions_path = submit::resolve(args.submissions.as_deref());
+    let mut submissions = load_toml::<Submissions>(&submissions_path)
+        .map_err(|err| format!("cannot load {}: {}", submissions_path.display(), err))?;
+
+    let client = client()?;
+    let outcome = submit::submit(&client, &mut submissions, year.year, args.day, args.part, &answer).map_err(|err| err.to_string())?;
+    save_toml(&submissions_path, &submissions).map_err(|err| format!("cannot save {}: {}", submissions_path.display(), err))?;
+    let status = match &outcome {
+        Outcome::Right => outcome.to_string().green(),
+        Outcome::Wrong(_) => outcome.to_string().red().bold(),
+        _ => outcome.to_string().yellow(),
+    };
+    eprintln!("day {} part {}: {} {}", args.day, args.part, answer, status);
+
+    if let (Outcome::Right, Some(hash)) = (&outcome, hash) {
+        let answers_path = answers::resolve(None);
+        let mut answers = load_toml::<Answers>(&answers_path).map_err(|err| format!("cannot load {}: {}", answers_path.display(), err))?;
+        if answers.record(year.year, args.day, args.part, &hash, &answer) {
+            save_toml(&answers_path, &answers).map_err(|err| format!("cannot save {}: {}", answers_path.display(), err))?;
+        }
+    }
+    match outcome {
+        Outcome::Right => Ok(()),
+        _ => Err(format!("day {} part {} is not solved", args.day, args.part)),
+    }
+}
+
+fn generate(year: &Year, day: u8, knobs: Knobs) -> Result<(), String> {
+    let input = (year.generate)(day, &knobs)?;
+    let mut stdout = stdio::stdout().lock();
+    stdout.write_all(input.as_bytes()).and_then(|_| stdout.flush()).map_err(|err| format!("cannot write input: {}", err))
+}
+
+fn main() {
+    let args = match parse_args(env::args().skip(1)) {
+        Ok(args) => args,
+        Err(err) => {
+            eprintln!("{}\n{}", err, USAGE);
+            exit(2);
+        }
+    };
+    log::set_level(args.level);
+    let result = year(args.year).and_then(|year| {
+        let mut settings = Settings::default();
+        let mut custom = false;
+        for (key, value) in &args.settings {
+            custom |= (year.configure)(&mut settings, key, value).map_err(|err| format!("--set {}: {}", key, err))?;
+        }
+        Ok((year, settings, custom))
+    });
+    let result = result.and_then(|(year, settings, custom)| match args.command {
+        Command::Run(args) => run(year, args, &settings, custom),
+        Command::Bench(args) => bench(year, args, &settings),
+        Command::Profile(args) => profile(year, args, &settings),
+        Command::New { day } => new(year, day),
+        Command::Fetch { days } => fetch(year, days),
+        Command::Submit(_) if custom => Err("submit needs the default settings, the puzzle knows nothing of --set".to_string()),
+        Command::Submit(args) => submit(year, args),
+        Command::Generate { day, knobs } => generate(year, day, knobs),
+    });
+    if let Err(err) = result {
+        eprintln!("{}", err);
+        exit(1);
+    }
+}